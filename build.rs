@@ -0,0 +1,188 @@
+/// Generates the solution registry for the main binary from the day modules in `src/bin`.
+/// Each module is included with `#[path]`, so solutions can be called without spawning a process.
+/// Modules are named `_<year>_<day>` after their `<year>-<day>.rs` file, which is what the `solution!` macro reads the year from.
+///
+/// Also generates the example tests of each solution from its manifest in `data/<year>/examples/<day>.json`.
+/// The `solution!` macro includes them from `examples/<bin name>.rs` in the output directory.
+use std::{
+    collections::{HashMap, HashSet},
+    env,
+    fmt::Write,
+    fs,
+    path::Path,
+    str::FromStr,
+};
+
+use tinyjson::JsonValue;
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    let mut modules: Vec<(String, String)> = fs::read_dir(bin_dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let stem = path.file_stem()?.to_str()?.to_string();
+
+            let (year, day) = stem.split_once('-')?;
+
+            let is_day_module = path.extension()? == "rs"
+                && year.len() == 4
+                && day.len() == 2
+                && year.chars().chain(day.chars()).all(|c| c.is_ascii_digit());
+
+            if is_day_module {
+                Some((format!("_{year}_{day}"), path.to_str()?.to_string()))
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    modules.sort_unstable();
+
+    // NOTE: each solution defines a global allocator when `dhat-heap` is enabled,
+    // so the registry stays empty in that case. `cargo solve --dhat` runs the bins directly.
+    let mut out = String::new();
+
+    for (name, path) in &modules {
+        writeln!(out, "#[cfg(not(feature = \"dhat-heap\"))]").unwrap();
+        writeln!(out, "#[allow(dead_code, clippy::all)]").unwrap();
+        writeln!(out, "#[path = {path:?}]").unwrap();
+        writeln!(out, "mod {name};").unwrap();
+    }
+
+    let solutions = modules
+        .iter()
+        .map(|(name, _)| format!("{name}::SOLUTION"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    writeln!(out, "#[cfg(not(feature = \"dhat-heap\"))]").unwrap();
+    writeln!(
+        out,
+        "pub fn registry() -> advent_of_code::template::registry::Registry {{"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "    advent_of_code::template::registry::Registry::new(vec![{solutions}])"
+    )
+    .unwrap();
+    writeln!(out, "}}").unwrap();
+
+    writeln!(out, "#[cfg(feature = \"dhat-heap\")]").unwrap();
+    writeln!(
+        out,
+        "pub fn registry() -> advent_of_code::template::registry::Registry {{"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "    advent_of_code::template::registry::Registry::default()"
+    )
+    .unwrap();
+    writeln!(out, "}}").unwrap();
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("solutions.rs"), out).unwrap();
+
+    write_example_tests(Path::new(&manifest_dir), Path::new(&out_dir), &modules);
+}
+
+/// Write the example tests of every solution. The main binary includes the solutions as well, but gets no tests.
+fn write_example_tests(manifest_dir: &Path, out_dir: &Path, modules: &[(String, String)]) {
+    let tests_dir = out_dir.join("examples");
+    fs::create_dir_all(&tests_dir).unwrap();
+    fs::write(tests_dir.join("advent_of_code.rs"), "").unwrap();
+
+    for (name, _) in modules {
+        let (year, day) = name[1..].split_once('_').unwrap();
+
+        let examples_dir = manifest_dir.join("data").join(year).join("examples");
+        if examples_dir.exists() {
+            println!("cargo:rerun-if-changed={}", examples_dir.display());
+        }
+
+        let manifest_path = examples_dir.join(format!("{day}.json"));
+        let examples = match fs::read_to_string(&manifest_path) {
+            Ok(s) => parse_manifest(&s)
+                .unwrap_or_else(|e| panic!("invalid manifest {}: {e}", manifest_path.display())),
+            Err(_) => vec![],
+        };
+
+        let mut out = String::new();
+        let mut test_names = HashSet::new();
+
+        for (file, part, expected) in examples {
+            let stem = file
+                .trim_end_matches(".txt")
+                .replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+            let test_name = format!("example_{stem}_part_{part}");
+
+            // NOTE: a second test with the same name would fail to compile with a confusing error.
+            if !test_names.insert(test_name.clone()) {
+                panic!(
+                    "invalid manifest {}: example {file:?} part {part} duplicates an earlier example.",
+                    manifest_path.display()
+                );
+            }
+
+            writeln!(out, "#[test]").unwrap();
+            writeln!(out, "fn {test_name}() {{").unwrap();
+            writeln!(
+                out,
+                "    advent_of_code::template::example_manifest::check(&super::SOLUTION, {file:?}, {part}, {expected:?});"
+            )
+            .unwrap();
+            writeln!(out, "}}").unwrap();
+        }
+
+        fs::write(tests_dir.join(format!("{year}-{day}.rs")), out).unwrap();
+    }
+}
+
+/// Read the `(file, part, expected)` entries of a manifest. See `template::example_manifest` for the format.
+fn parse_manifest(s: &str) -> Result<Vec<(String, u8, Option<String>)>, String> {
+    let json = JsonValue::from_str(s).map_err(|e| e.to_string())?;
+
+    let examples = json
+        .get::<HashMap<String, JsonValue>>()
+        .and_then(|x| x.get("examples"))
+        .and_then(|x| x.get::<Vec<JsonValue>>())
+        .ok_or("expected an object with an `examples` array.")?;
+
+    examples
+        .iter()
+        .map(|example| {
+            let example = example
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or("expected examples to be objects.")?;
+
+            let file = example
+                .get("file")
+                .and_then(|x| x.get::<String>())
+                .ok_or("expected example.file to be a string.")?;
+
+            let part = example
+                .get("part")
+                .and_then(|x| x.get::<f64>())
+                .filter(|x| **x == 1.0 || **x == 2.0)
+                .ok_or("expected example.part to be 1 or 2.")?;
+
+            let expected = match example.get("expected") {
+                Some(x) if !x.is_null() => Some(
+                    x.get::<String>()
+                        .ok_or("expected example.expected to be null or a string.")?
+                        .clone(),
+                ),
+                _ => None,
+            };
+
+            Ok((file.clone(), *part as u8, expected))
+        })
+        .collect()
+}
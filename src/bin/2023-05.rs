@@ -0,0 +1,70 @@
+use advent_of_code::intervals::{RangeMap, RangeSet};
+
+advent_of_code::solution!(5);
+
+fn parse(input: &str, seeds_as_ranges: bool) -> (RangeSet<u64>, Vec<RangeMap<u64>>) {
+    let mut lines = input.lines();
+    let seeds: Vec<u64> = lines
+        .next()
+        .unwrap()
+        .split(": ")
+        .nth(1)
+        .unwrap()
+        .split(' ')
+        .map(|value| value.parse::<u64>().unwrap())
+        .collect();
+    let mut maps: Vec<RangeMap<u64>> = Vec::new();
+    let mut map = RangeMap::new();
+
+    lines.next(); // skip the first blank line
+    for line in lines {
+        if line.is_empty() {
+            // Finish the maps
+            maps.push(std::mem::take(&mut map));
+            continue;
+        }
+
+        if line.ends_with("map:") {
+            continue;
+        }
+
+        // If we get this far, the line should contain map values
+        let values: Vec<u64> = line
+            .split(' ')
+            .map(|value| value.parse::<u64>().unwrap())
+            .collect();
+        map.insert(values[1]..values[1] + values[2], values[0]);
+    }
+
+    // Make sure to push the very last map
+    maps.push(map);
+
+    if seeds_as_ranges {
+        // Interpret the seed values as pairs of range start and range length
+        let seed_ranges = seeds
+            .chunks_exact(2)
+            .map(|pair| pair[0]..pair[0] + pair[1])
+            .collect();
+
+        return (seed_ranges, maps);
+    }
+
+    (seeds.iter().map(|&seed| seed..seed + 1).collect(), maps)
+}
+
+fn solve(input: &str, as_ranges: bool) -> Option<u64> {
+    let (seeds, maps) = parse(input, as_ranges);
+
+    // Map whole ranges of seeds at once instead of every single seed
+    maps.iter()
+        .fold(seeds, |values, map| map.apply_set(&values))
+        .min()
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    solve(input, false)
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    solve(input, true)
+}
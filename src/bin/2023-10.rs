@@ -0,0 +1,100 @@
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use advent_of_code::geometry::Polygon;
+use advent_of_code::grid::Grid;
+use advent_of_code::point::{Direction, Point2};
+
+advent_of_code::solution!(10);
+
+const N: Direction = Direction::North;
+const E: Direction = Direction::East;
+const S: Direction = Direction::South;
+const W: Direction = Direction::West;
+
+lazy_static! {
+    /// Map of pipe symbols to maps of possible entry and exit directions.
+    static ref PIPE_MAP: HashMap<char, HashMap<Direction, Direction>> = HashMap::from([
+        ('|', HashMap::from([(N, N), (S, S)])),
+        ('-', HashMap::from([(E, E), (W, W)])),
+        ('F', HashMap::from([(N, E), (W, S)])),
+        ('7', HashMap::from([(E, S), (N, W)])),
+        ('J', HashMap::from([(S, W), (E, N)])),
+        ('L', HashMap::from([(W, N), (S, E)])),
+    ]);
+
+    static ref CORNERS: Vec<char> = vec!['F', '7', 'J', 'L'];
+}
+
+fn solve(input: &str, calculate_area: bool) -> Option<u32> {
+    let map: Grid<char> = input.parse().unwrap();
+
+    // Find S
+    let mut coords = Point2::from_index(map.find(&'S')?).unwrap();
+
+    let mut visited: HashSet<Point2> = HashSet::new();
+    visited.insert(coords);
+
+    let mut vertices: Vec<Point2> = vec![];
+    vertices.push(coords);
+
+    let mut next_direction = N;
+    for direction in Direction::ALL {
+        let destination = coords + direction;
+        let Some(tile) = map.get(destination.x, destination.y) else {
+            // Out of bounds
+            continue;
+        };
+
+        if let Some(pipe) = PIPE_MAP.get(tile) {
+            if let Some(_) = pipe.get(&direction) {
+                // Pipe can be entered from this direction
+                next_direction = direction;
+                break;
+            }
+        }
+    }
+
+    let mut distance = 1;
+    loop {
+        coords += next_direction;
+        if visited.contains(&coords) {
+            // Circuit completed
+            break;
+        }
+
+        let pipe = &map[coords];
+
+        if CORNERS.contains(pipe) {
+            vertices.push(coords);
+        }
+
+        visited.insert(coords);
+
+        next_direction = PIPE_MAP
+            .get(pipe)
+            .unwrap()
+            .get(&next_direction)
+            .unwrap()
+            .clone();
+        distance += 1;
+    }
+
+    if !calculate_area {
+        return Some(distance / 2);
+    }
+
+    // The loop runs through the centers of its tiles, so enclosed tiles are lattice points inside of it
+    let polygon = Polygon::new(vertices);
+
+    Some(polygon.interior_points() as u32)
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    solve(input, false)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    solve(input, true)
+}
@@ -1,8 +1,12 @@
-advent_of_code::solution!(13);
+use advent_of_code::template::Solution;
 
-fn parse(input: &str) -> Vec<Vec<Vec<char>>> {
-    let mut patterns: Vec<Vec<Vec<char>>> = vec![];
-    let mut pattern: Vec<Vec<char>> = vec![];
+advent_of_code::solution!(13, Day13);
+
+type Pattern = Vec<Vec<char>>;
+
+fn parse(input: &str) -> Vec<Pattern> {
+    let mut patterns: Vec<Pattern> = vec![];
+    let mut pattern: Pattern = vec![];
     for line in input.lines() {
         if line.is_empty() {
             patterns.push(pattern);
@@ -20,7 +24,7 @@ fn parse(input: &str) -> Vec<Vec<Vec<char>>> {
     patterns
 }
 
-fn try_fold(pattern: &Vec<Vec<char>>, rotate: bool, with_smudges: bool) -> Option<u32> {
+fn try_fold(pattern: &Pattern, rotate: bool, with_smudges: bool) -> Option<u32> {
     let mut p: Vec<Vec<char>> = vec![];
 
     if rotate {
@@ -70,11 +74,10 @@ fn try_fold(pattern: &Vec<Vec<char>>, rotate: bool, with_smudges: bool) -> Optio
     None
 }
 
-fn solve(input: &str, with_smudges: bool) -> Option<u32> {
-    let patterns = parse(input);
+fn solve(patterns: &[Pattern], with_smudges: bool) -> Option<u32> {
     let mut reflected_cols = 0;
     let mut reflected_rows = 0;
-    for pattern in &patterns {
+    for pattern in patterns {
         if let Some(rows) = try_fold(pattern, false, with_smudges) {
             reflected_rows += rows;
         } else if let Some(cols) = try_fold(pattern, true, with_smudges) {
@@ -85,27 +88,21 @@ fn solve(input: &str, with_smudges: bool) -> Option<u32> {
     Some((reflected_rows * 100 + reflected_cols) as u32)
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    solve(input, false)
-}
+struct Day13;
 
-pub fn part_two(input: &str) -> Option<u32> {
-    solve(input, true)
-}
+impl Solution for Day13 {
+    type Parsed = Vec<Pattern>;
+    type Answer = u32;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    fn parse(input: &str) -> Self::Parsed {
+        parse(input)
+    }
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(405));
+    fn part_one(patterns: &Self::Parsed) -> Option<Self::Answer> {
+        solve(patterns, false)
     }
 
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(400));
+    fn part_two(patterns: &Self::Parsed) -> Option<Self::Answer> {
+        solve(patterns, true)
     }
 }
@@ -1,52 +1,52 @@
 use std::collections::HashMap;
 
+use advent_of_code::grid::Grid;
+
 advent_of_code::solution!(14);
 
-type Platform = Vec<Vec<char>>;
+type Platform = Grid<char>;
 
 fn tilt(platform: &mut Platform, offset: isize, horizontally: bool) {
     let y_indices: Vec<usize> = if horizontally {
-        (0..platform.len()).collect()
+        (0..platform.height()).collect()
     } else {
         match offset {
-            -1 => (1..platform.len()).collect(),
-            1 => (0..platform.len() - 1).rev().collect(),
+            -1 => (1..platform.height()).collect(),
+            1 => (0..platform.height() - 1).rev().collect(),
             _ => panic!("Invalid offset"),
         }
     };
 
     let x_indices: Vec<usize> = if horizontally {
         match offset {
-            -1 => (1..platform[0].len()).collect(),
-            1 => (0..platform[0].len() - 1).rev().collect(),
+            -1 => (1..platform.width()).collect(),
+            1 => (0..platform.width() - 1).rev().collect(),
             _ => panic!("Invalid offset"),
         }
     } else {
-        (0..platform[0].len()).collect()
+        (0..platform.width()).collect()
     };
 
     loop {
         let mut changed = false;
         for y in &y_indices {
             for x in &x_indices {
-                if platform[*y][*x] != 'O' {
+                if platform[(*x, *y)] != 'O' {
                     continue;
                 }
 
                 if horizontally {
                     let move_to = (*x as isize + offset) as usize;
 
-                    if platform[*y][move_to] == '.' {
-                        platform[*y][*x] = '.';
-                        platform[*y][move_to] = 'O';
+                    if platform[(move_to, *y)] == '.' {
+                        platform.swap((*x, *y), (move_to, *y));
                         changed = true;
                     }
                 } else {
                     let move_to = (*y as isize + offset) as usize;
 
-                    if platform[move_to][*x] == '.' {
-                        platform[*y][*x] = '.';
-                        platform[move_to][*x] = 'O';
+                    if platform[(*x, move_to)] == '.' {
+                        platform.swap((*x, *y), (*x, move_to));
                         changed = true;
                     }
                 }
@@ -60,7 +60,7 @@ fn tilt(platform: &mut Platform, offset: isize, horizontally: bool) {
 }
 
 fn solve(input: &str, do_cycles: bool) -> Option<u32> {
-    let mut platform: Platform = input.lines().map(|l| l.chars().collect()).collect();
+    let mut platform: Platform = input.parse().unwrap();
     let mut cache: HashMap<Platform, usize> = HashMap::new();
     let mut cycles = 0;
     let mut pattern_ends_at = 0;
@@ -100,8 +100,8 @@ fn solve(input: &str, do_cycles: bool) -> Option<u32> {
             continue;
         }
 
-        for (i, row) in value.iter().enumerate() {
-            let row_load = platform.len() - i;
+        for (i, row) in value.rows().enumerate() {
+            let row_load = platform.height() - i;
             load += row.iter().filter(|c| **c == 'O').count() * row_load;
         }
     }
@@ -116,20 +116,3 @@ pub fn part_one(input: &str) -> Option<u32> {
 pub fn part_two(input: &str) -> Option<u32> {
     solve(input, true)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(136));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(64));
-    }
-}
@@ -3,30 +3,25 @@ use std::cmp::max;
 use std::collections::HashSet;
 use std::result::Result;
 
-advent_of_code::solution!(16);
+use advent_of_code::grid::Grid;
+use advent_of_code::point::{Direction, Point2};
 
-type Grid = Vec<Vec<char>>;
-type Position = (usize, usize);
-type Direction = (isize, isize);
-type VisitedSet = HashSet<(Position, Direction)>;
+advent_of_code::solution!(16);
 
-const NORTH: Direction = (0, -1);
-const SOUTH: Direction = (0, 1);
-const EAST: Direction = (1, 0);
-const WEST: Direction = (-1, 0);
+type VisitedSet = HashSet<(Point2, Direction)>;
 
 struct Beam<'a> {
-    grid: &'a Grid,
+    grid: &'a Grid<char>,
     visited: &'a RefCell<VisitedSet>,
-    position: Position,
+    position: Point2,
     direction: Direction,
 }
 
 impl<'a> Beam<'a> {
     fn new(
-        grid: &'a Grid,
+        grid: &'a Grid<char>,
         visited: &'a RefCell<VisitedSet>,
-        position: Position,
+        position: Point2,
         direction: Direction,
     ) -> Self {
         Self {
@@ -38,29 +33,18 @@ impl<'a> Beam<'a> {
     }
 
     fn get_current_tile(&self) -> char {
-        self.grid[self.position.1][self.position.0]
+        self.grid[self.position]
     }
 
     fn can_advance(&self, direction: Direction) -> bool {
-        let (px, py) = self.position;
-        let (dx, dy) = direction;
-        let (next_x, next_y) = ((px as isize + dx), (py as isize + dy));
-
-        (0..self.grid[0].len()).contains(&(next_x as usize))
-            && (0..self.grid.len()).contains(&(next_y as usize))
-            && !self
-                .visited
-                .borrow()
-                .contains(&(((next_x as usize), (next_y as usize)), direction))
+        let next = self.position + direction;
+
+        self.grid.contains(next.x, next.y) && !self.visited.borrow().contains(&(next, direction))
     }
 
     fn advance(&mut self) {
         while self.can_advance(self.direction) {
-            let (px, py) = self.position;
-            let (dx, dy) = self.direction;
-            let (next_x, next_y) = ((px as isize + dx), (py as isize + dy));
-
-            self.position = ((next_x as usize), (next_y as usize));
+            self.position += self.direction;
             self.visited
                 .borrow_mut()
                 .insert((self.position, self.direction));
@@ -87,27 +71,25 @@ impl<'a> Beam<'a> {
         let current_tile = self.get_current_tile();
         if current_tile != '.' {
             self.direction = match current_tile {
-                '|' if self.direction == WEST || self.direction == EAST => {
-                    new_beam = self.try_split(NORTH);
-                    SOUTH
+                '|' if self.direction == Direction::West || self.direction == Direction::East => {
+                    new_beam = self.try_split(Direction::North);
+                    Direction::South
                 }
-                '-' if self.direction == NORTH || self.direction == SOUTH => {
-                    new_beam = self.try_split(EAST);
-                    WEST
+                '-' if self.direction == Direction::North || self.direction == Direction::South => {
+                    new_beam = self.try_split(Direction::East);
+                    Direction::West
                 }
                 '/' => match self.direction {
-                    EAST => NORTH,
-                    SOUTH => WEST,
-                    NORTH => EAST,
-                    WEST => SOUTH,
-                    _ => panic!("Invalid direction!"),
+                    Direction::East => Direction::North,
+                    Direction::South => Direction::West,
+                    Direction::North => Direction::East,
+                    Direction::West => Direction::South,
                 },
                 '\\' => match self.direction {
-                    EAST => SOUTH,
-                    SOUTH => EAST,
-                    NORTH => WEST,
-                    WEST => NORTH,
-                    _ => panic!("Invalid direction!"),
+                    Direction::East => Direction::South,
+                    Direction::South => Direction::East,
+                    Direction::North => Direction::West,
+                    Direction::West => Direction::North,
                 },
                 _ => self.direction,
             };
@@ -122,11 +104,11 @@ impl<'a> Beam<'a> {
     }
 }
 
-fn parse(input: &str) -> Grid {
-    input.lines().map(|line| line.chars().collect()).collect()
+fn parse(input: &str) -> Grid<char> {
+    input.parse().unwrap()
 }
 
-fn simulate(grid: &Grid, start_position: Position, start_direction: Direction) -> Option<u32> {
+fn simulate(grid: &Grid<char>, start_position: Point2, start_direction: Direction) -> Option<u32> {
     let visited: RefCell<VisitedSet> = RefCell::new(HashSet::new());
     visited
         .borrow_mut()
@@ -156,7 +138,7 @@ fn simulate(grid: &Grid, start_position: Position, start_direction: Direction) -
         }
     }
 
-    let mut energised: HashSet<Position> = HashSet::new();
+    let mut energised: HashSet<Point2> = HashSet::new();
     for (position, _) in visited.borrow().iter() {
         energised.insert(*position);
     }
@@ -166,42 +148,36 @@ fn simulate(grid: &Grid, start_position: Position, start_direction: Direction) -
 
 pub fn part_one(input: &str) -> Option<u32> {
     let grid = parse(input);
-    simulate(&grid, (0, 0), EAST)
+    simulate(&grid, Point2::ORIGIN, Direction::East)
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
     let grid = parse(input);
     let mut max_energised: u32 = 0;
 
-    for x in 0..grid[0].len() {
-        max_energised = max(simulate(&grid, (x, 0), SOUTH)?, max_energised);
-        max_energised = max(simulate(&grid, (x, grid.len() - 1), NORTH)?, max_energised);
-    }
+    let (width, height) = (grid.width() as isize, grid.height() as isize);
 
-    for y in 0..grid.len() {
-        max_energised = max(simulate(&grid, (0, y), EAST)?, max_energised);
+    for x in 0..width {
         max_energised = max(
-            simulate(&grid, (grid[0].len() - 1, y), WEST)?,
+            simulate(&grid, Point2::new(x, 0), Direction::South)?,
+            max_energised,
+        );
+        max_energised = max(
+            simulate(&grid, Point2::new(x, height - 1), Direction::North)?,
             max_energised,
         );
     }
 
-    Some(max_energised as u32)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(46));
+    for y in 0..height {
+        max_energised = max(
+            simulate(&grid, Point2::new(0, y), Direction::East)?,
+            max_energised,
+        );
+        max_energised = max(
+            simulate(&grid, Point2::new(width - 1, y), Direction::West)?,
+            max_energised,
+        );
     }
 
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(51));
-    }
+    Some(max_energised as u32)
 }
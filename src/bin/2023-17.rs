@@ -0,0 +1,78 @@
+use advent_of_code::grid::Grid;
+use advent_of_code::point::{Direction, Point2};
+use advent_of_code::search::dijkstra;
+
+advent_of_code::solution!(17);
+
+#[derive(Clone, Eq, PartialEq, Hash)]
+struct State {
+    position: Point2,
+    entered_from: Direction,
+    straight_steps: u32,
+}
+
+impl State {
+    fn new(position: Point2, entered_from: Direction, straight_steps: u32) -> Self {
+        Self {
+            position,
+            entered_from,
+            straight_steps,
+        }
+    }
+
+    /// States the crucible can move to, along with the heat lost by entering them.
+    fn successors(
+        &self,
+        grid: &Grid<u32>,
+        min_straight_steps: u32,
+        max_straight_steps: u32,
+    ) -> Vec<(State, u32)> {
+        Direction::ALL
+            .into_iter()
+            .filter(|direction| *direction != self.entered_from.opposite())
+            .filter(|direction| {
+                if *direction == self.entered_from {
+                    self.straight_steps < max_straight_steps
+                } else {
+                    self.straight_steps >= min_straight_steps
+                }
+            })
+            .filter_map(|direction| {
+                let position = self.position + direction;
+                let cost = *grid.get(position.x, position.y)?;
+                let straight_steps = if direction == self.entered_from {
+                    self.straight_steps + 1
+                } else {
+                    1
+                };
+
+                Some((State::new(position, direction, straight_steps), cost))
+            })
+            .collect()
+    }
+}
+
+// Dijkstra's algorithm over states that consider steps taken in a direction as well as the position.
+fn solve(input: &str, min_straight_steps: u32, max_straight_steps: u32) -> Option<u32> {
+    let grid = Grid::parse_with(input, |c| c.to_digit(10).unwrap()).unwrap();
+
+    let end = Point2::new(grid.width() as isize - 1, grid.height() as isize - 1);
+    let starts = [Direction::East, Direction::South]
+        .map(|direction| State::new(Point2::ORIGIN, direction, 0));
+
+    let path = dijkstra(
+        starts,
+        |state| state.successors(&grid, min_straight_steps, max_straight_steps),
+        |state| state.position == end && state.straight_steps >= min_straight_steps,
+    )?;
+
+    Some(path.cost)
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    solve(input, 0, 3)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    solve(input, 4, 10)
+}
@@ -0,0 +1,49 @@
+use advent_of_code::geometry::Polygon;
+use advent_of_code::point::Direction;
+
+advent_of_code::solution!(18);
+
+fn solve(input: &str, p2: bool) -> Option<i64> {
+    let mut steps: Vec<(Direction, isize)> = vec![];
+    for instruction in input.lines() {
+        let mut parts = instruction.split_whitespace();
+
+        let direction: &str;
+        let distance: isize;
+
+        if !p2 {
+            direction = parts.next().unwrap();
+            distance = parts.next().unwrap().parse().unwrap();
+        } else {
+            parts.next();
+            parts.next();
+            let mut hex = parts.next().unwrap();
+            hex = &hex[2..hex.len() - 1];
+            distance = isize::from_str_radix(&hex[0..=4], 16).unwrap();
+            direction = &hex[5..];
+        }
+
+        let direction = match direction {
+            "U" | "3" => Direction::North,
+            "R" | "0" => Direction::East,
+            "D" | "1" => Direction::South,
+            "L" | "2" => Direction::West,
+            _ => unreachable!(),
+        };
+
+        steps.push((direction, distance));
+    }
+
+    // The lagoon is made of the dug out boundary and everything inside of it
+    let lagoon = Polygon::from_steps(steps);
+
+    i64::try_from(lagoon.interior_points() + lagoon.boundary_points()).ok()
+}
+
+pub fn part_one(input: &str) -> Option<i64> {
+    solve(input, false)
+}
+
+pub fn part_two(input: &str) -> Option<i64> {
+    solve(input, true)
+}
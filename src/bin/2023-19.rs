@@ -1,8 +1,6 @@
+use advent_of_code::intervals::RangeSet;
 use lazy_static::lazy_static;
-use std::{
-    cmp::{max, min},
-    collections::HashMap,
-};
+use std::collections::HashMap;
 
 advent_of_code::solution!(19);
 
@@ -211,7 +209,7 @@ pub fn part_one(input: &str) -> Option<u64> {
 
 // Heavily based on HyperNeutrino's solution: https://www.youtube.com/watch?v=3RwIpUegdU4
 fn count_accepted_combos(
-    ranges: &Vec<(u64, u64)>,
+    ranges: &Vec<RangeSet<u64>>,
     status: String,
     workflows: &HashMap<String, Workflow>,
 ) -> u64 {
@@ -220,12 +218,7 @@ fn count_accepted_combos(
     }
 
     if status == *ACCEPTED {
-        let mut combos = 1;
-        for range in ranges {
-            combos *= range.1 - range.0 + 1;
-        }
-
-        return combos;
+        return ranges.iter().map(|range| range.len()).product();
     }
 
     let workflow = workflows.get(&status).unwrap();
@@ -236,19 +229,15 @@ fn count_accepted_combos(
         let step = &workflow.steps[i];
         let rule = step.rule.as_ref().unwrap();
 
-        let (start, end) = ranges_clone[rule.category];
-
-        let valid_range: (u64, u64);
-        let invalid_range: (u64, u64);
-        if rule.operator == Operator::LessThan {
-            valid_range = (start, min(rule.target_rating - 1, end));
-            invalid_range = (max(rule.target_rating, start), end);
+        let range = &ranges_clone[rule.category];
+        let (valid_range, invalid_range) = if rule.operator == Operator::LessThan {
+            range.split_at(rule.target_rating)
         } else {
-            valid_range = (max(rule.target_rating + 1, start), end);
-            invalid_range = (start, min(rule.target_rating, end));
-        }
+            let (below, above) = range.split_at(rule.target_rating + 1);
+            (above, below)
+        };
 
-        if valid_range.0 <= valid_range.1 {
+        if !valid_range.is_empty() {
             ranges_clone[rule.category] = valid_range;
             combos += count_accepted_combos(
                 &ranges_clone,
@@ -257,7 +246,7 @@ fn count_accepted_combos(
             );
         }
 
-        if invalid_range.0 <= invalid_range.1 {
+        if !invalid_range.is_empty() {
             ranges_clone[rule.category] = invalid_range;
         } else {
             leftover_ranges = false;
@@ -279,23 +268,6 @@ fn count_accepted_combos(
 
 pub fn part_two(input: &str) -> Option<u64> {
     let (workflows, _) = parse(input);
-    let ranges = vec![(1 as u64, 4000 as u64); 4];
+    let ranges = vec![RangeSet::from(1..4001); 4];
     Some(count_accepted_combos(&ranges, "in".to_string(), &workflows))
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(19114));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(167409079868000));
-    }
-}
@@ -1,31 +1,25 @@
 use std::collections::{HashSet, VecDeque};
 
+use advent_of_code::grid::Grid;
+
 advent_of_code::solution!(21);
 
 type Position = (isize, isize);
 
 struct Map {
-    value: Vec<Vec<char>>,
+    value: Grid<char>,
     size: isize,
 }
 
 impl Map {
     fn new(input: &str) -> Self {
-        let value: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
-        let size = value.len() as isize;
+        let value: Grid<char> = input.parse().unwrap();
+        let size = value.height() as isize;
         Map { value, size }
     }
 
-    fn contains(&self, x: isize, y: isize) -> bool {
-        x >= 0 && x < self.size && y >= 0 && y < self.size
-    }
-
     fn at(&self, x: isize, y: isize) -> Option<char> {
-        if !self.contains(x, y) {
-            return None;
-        }
-
-        Some(self.value[y as usize][x as usize])
+        self.value.get(x, y).copied()
     }
 }
 
@@ -116,20 +110,3 @@ pub fn part_two(input: &str) -> Option<u64> {
             + map_width * large.iter().sum::<u64>(),
     )
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(42));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(470149860542205));
-    }
-}
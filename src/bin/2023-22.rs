@@ -3,6 +3,8 @@ use lazy_static::lazy_static;
 use std::sync::Mutex;
 use std::{collections::HashSet, ops::RangeInclusive};
 
+use advent_of_code::point::Point3;
+
 advent_of_code::solution!(22);
 
 lazy_static! {
@@ -12,18 +14,16 @@ lazy_static! {
     static ref UNSAFE_BRICK_INDICES: Mutex<HashSet<usize>> = Mutex::new(HashSet::new());
 }
 
-type Position = (usize, usize, usize);
-
 #[derive(Clone, PartialEq, Eq)]
 struct Brick {
-    positions: Vec<Position>,
-    layers: RangeInclusive<usize>,
+    positions: Vec<Point3>,
+    layers: RangeInclusive<i64>,
 }
 
 impl Brick {
     fn move_down(&mut self) {
         for position in &mut self.positions {
-            position.2 -= 1;
+            position.z -= 1;
         }
 
         self.layers = self.layers.start() - 1..=self.layers.end() - 1;
@@ -31,7 +31,7 @@ impl Brick {
 
     // This could be optimised by partitioning on the Z axis to limit the number of bricks tested
     fn is_settled(&self, bricks: &Vec<Brick>, ignore: Option<&Brick>) -> bool {
-        if self.positions.iter().any(|p| p.2 == 1) {
+        if self.positions.iter().any(|p| p.z == 1) {
             return true;
         }
 
@@ -47,10 +47,7 @@ impl Brick {
             }
 
             for position in &other.positions {
-                if self
-                    .positions
-                    .contains(&(position.0, position.1, position.2 + 1))
-                {
+                if self.positions.contains(&(*position + Point3::new(0, 0, 1))) {
                     return true;
                 }
             }
@@ -86,7 +83,7 @@ fn parse(input: &str) {
         for x in start_x..=end_x {
             for y in start_y..=end_y {
                 for z in start_z..=end_z {
-                    brick.positions.push((x, y, z));
+                    brick.positions.push(Point3::new(x, y, z));
                 }
             }
         }
@@ -97,10 +94,10 @@ fn parse(input: &str) {
     bricks.sort_by(|a, b| {
         a.positions
             .iter()
-            .map(|p| p.2)
+            .map(|p| p.z)
             .min()
             .unwrap()
-            .cmp(&b.positions.iter().map(|p| p.2).min().unwrap())
+            .cmp(&b.positions.iter().map(|p| p.z).min().unwrap())
     });
 }
 
@@ -171,20 +168,3 @@ pub fn part_two(_: &str) -> Option<u32> {
 
     Some(total_fell)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(5));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(7));
-    }
-}
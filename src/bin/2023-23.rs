@@ -1,22 +1,20 @@
 use std::collections::{HashMap, HashSet};
 
+use advent_of_code::grid::Grid;
+use advent_of_code::point::{Direction, Point2};
+
 advent_of_code::solution!(23);
 
-type Position = (isize, isize);
+const START: Point2 = Point2::new(1, 0);
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct Connection {
-    to: Position,
+    to: Point2,
     distance: usize,
     accessible: bool,
 }
 
-const N: Position = (0, -1);
-const E: Position = (1, 0);
-const S: Position = (0, 1);
-const W: Position = (-1, 0);
-
-fn find_connection(map: &Vec<Vec<char>>, path: &mut Vec<Position>) -> Option<(usize, Position)> {
+fn find_connection(map: &Grid<char>, path: &mut Vec<Point2>) -> Option<(usize, Point2)> {
     let mut position = *path.last().unwrap();
 
     let neighbours = get_walkable_neighbours(map, path, false);
@@ -38,29 +36,26 @@ fn find_connection(map: &Vec<Vec<char>>, path: &mut Vec<Position>) -> Option<(us
     Some((path.len(), position.clone()))
 }
 
-fn contract_map(map: &Vec<Vec<char>>) -> HashMap<Position, Vec<Connection>> {
-    let mut nodes: HashMap<Position, Vec<Connection>> = HashMap::new();
+fn contract_map(map: &Grid<char>) -> HashMap<Point2, Vec<Connection>> {
+    let mut nodes: HashMap<Point2, Vec<Connection>> = HashMap::new();
 
     // Insert start and end nodes, even though they're not junctions
-    nodes.insert((1, 0), vec![]);
-    nodes.insert((map[0].len() as isize - 2, map.len() as isize - 1), vec![]);
+    nodes.insert(START, vec![]);
+    nodes.insert(end(map), vec![]);
 
     // Find the junction nodes
-    for y in 0..map.len() {
-        for x in 0..map[0].len() {
-            if map[y][x] != '.' {
+    for (index, tile) in map.iter() {
+        if *tile != '.' {
+            continue;
+        }
+
+        let position = Point2::from_index(index).unwrap();
+        if let Some(neighbours) = get_walkable_neighbours(map, &vec![position], true) {
+            if neighbours.len() == 1 {
                 continue;
             }
 
-            if let Some(neighbours) =
-                get_walkable_neighbours(map, &vec![(x as isize, y as isize)], true)
-            {
-                if neighbours.len() == 1 {
-                    continue;
-                }
-
-                nodes.insert((x as isize, y as isize), vec![]);
-            }
+            nodes.insert(position, vec![]);
         }
     }
 
@@ -82,38 +77,32 @@ fn contract_map(map: &Vec<Vec<char>>) -> HashMap<Position, Vec<Connection>> {
 }
 
 fn get_walkable_neighbours(
-    map: &Vec<Vec<char>>,
-    path: &Vec<Position>,
+    map: &Grid<char>,
+    path: &Vec<Point2>,
     slopes_only: bool,
-) -> Option<Vec<(Position, bool)>> {
+) -> Option<Vec<(Point2, bool)>> {
     let position = path[path.len() - 1];
     let mut neighbours = vec![];
 
-    for direction in vec![N, E, S, W] {
-        let next = (position.0 + direction.0, position.1 + direction.1);
-        if next.0 < 0
-            || next.1 < 0
-            || next.0 >= map[0].len() as isize
-            || next.1 >= map.len() as isize
-        {
+    for direction in Direction::ALL {
+        let next = position + direction;
+        let Some(&neighbour) = map.get(next.x, next.y) else {
             continue;
-        }
+        };
 
         if path.contains(&next) {
             continue;
         }
 
-        let neighbour = map[next.1 as usize][next.0 as usize];
-
         if neighbour == '#' || slopes_only && neighbour == '.' {
             continue;
         }
 
         let accessible = match neighbour {
-            '^' if direction != N => false,
-            '>' if direction != E => false,
-            'v' if direction != S => false,
-            '<' if direction != W => false,
+            '^' if direction != Direction::North => false,
+            '>' if direction != Direction::East => false,
+            'v' if direction != Direction::South => false,
+            '<' if direction != Direction::West => false,
             _ => true,
         };
 
@@ -128,9 +117,9 @@ fn get_walkable_neighbours(
 }
 
 fn find_path_lengths(
-    graph: &HashMap<Position, Vec<Connection>>,
-    start: Position,
-    end: Position,
+    graph: &HashMap<Point2, Vec<Connection>>,
+    start: Point2,
+    end: Point2,
     ignore_slopes: bool,
 ) -> Vec<usize> {
     let mut visited = HashSet::new();
@@ -152,11 +141,11 @@ fn find_path_lengths(
 }
 
 fn dfs(
-    graph: &HashMap<Position, Vec<Connection>>,
-    current: Position,
-    end: Position,
-    visited: &mut HashSet<Position>,
-    current_path: &mut Vec<Position>,
+    graph: &HashMap<Point2, Vec<Connection>>,
+    current: Point2,
+    end: Point2,
+    visited: &mut HashSet<Point2>,
+    current_path: &mut Vec<Point2>,
     path_lengths: &mut Vec<usize>,
     current_distance: usize,
     ignore_slopes: bool,
@@ -195,16 +184,15 @@ fn dfs(
     current_path.pop();
 }
 
+fn end(map: &Grid<char>) -> Point2 {
+    Point2::new(map.width() as isize - 2, map.height() as isize - 1)
+}
+
 fn solve(input: &str, ignore_slopes: bool) -> Option<u32> {
-    let map = &input.lines().map(|line| line.chars().collect()).collect();
-    let nodes = contract_map(map);
+    let map: Grid<char> = input.parse().unwrap();
+    let nodes = contract_map(&map);
 
-    let lengths = find_path_lengths(
-        &nodes,
-        (1, 0),
-        (map[0].len() as isize - 2, map.len() as isize - 1),
-        ignore_slopes,
-    );
+    let lengths = find_path_lengths(&nodes, START, end(&map), ignore_slopes);
 
     Some(*lengths.iter().max().unwrap() as u32)
 }
@@ -216,20 +204,3 @@ pub fn part_one(input: &str) -> Option<u32> {
 pub fn part_two(input: &str) -> Option<u32> {
     solve(input, true)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(94));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(154));
-    }
-}
@@ -0,0 +1,241 @@
+//! Polygons on a grid whose vertices are lattice points: their area, boundary and interior.
+//!
+//! Areas are computed exactly with the shoelace formula in `i128`, so that the large polygons of e.g. day 18 of 2023 fit.
+//!
+//! ```
+//! # use advent_of_code::geometry::Polygon;
+//! # use advent_of_code::point::{Direction, Point2};
+//! let square = Polygon::from_steps([
+//!     (Direction::East, 4),
+//!     (Direction::South, 4),
+//!     (Direction::West, 4),
+//!     (Direction::North, 4),
+//! ]);
+//! assert_eq!(square.area(), 16);
+//! assert_eq!(square.boundary_points(), 16);
+//! assert_eq!(square.interior_points(), 9);
+//! assert!(square.contains(Point2::new(2, 2)));
+//! assert!(!square.contains(Point2::new(5, 2)));
+//! ```
+use num::integer::gcd;
+
+use crate::point::{Direction, Point2};
+
+/// A closed polygon, given by its vertices in order. The last vertex connects back to the first one.
+/// Vertices can be in clockwise or counterclockwise order, and edges must not cross each other.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<Point2>,
+}
+
+impl Polygon {
+    pub fn new(mut vertices: Vec<Point2>) -> Self {
+        // NOTE: a path that returns to its start repeats the first vertex, which is not needed.
+        if vertices.len() > 1 && vertices.first() == vertices.last() {
+            vertices.pop();
+        }
+
+        Self { vertices }
+    }
+
+    /// Creates the polygon traced by walking the steps from the origin, each a direction and a number of cells.
+    pub fn from_steps(steps: impl IntoIterator<Item = (Direction, isize)>) -> Self {
+        let mut position = Point2::ORIGIN;
+        let mut vertices = vec![position];
+
+        for (direction, length) in steps {
+            position += direction.offset() * length;
+            vertices.push(position);
+        }
+
+        Self::new(vertices)
+    }
+
+    pub fn vertices(&self) -> &[Point2] {
+        &self.vertices
+    }
+
+    /// The edges of the polygon, including the one from the last vertex back to the first.
+    fn edges(&self) -> impl Iterator<Item = (Point2, Point2)> + '_ {
+        self.vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+            .map(|(&a, &b)| (a, b))
+    }
+
+    /// Twice the area of the polygon. Unlike the area itself, this is always a whole number.
+    pub fn double_area(&self) -> i128 {
+        self.edges()
+            .map(|(a, b)| a.x as i128 * b.y as i128 - b.x as i128 * a.y as i128)
+            .sum::<i128>()
+            .abs()
+    }
+
+    /// The area of the polygon. This is exact if all edges are horizontal or vertical, and rounded down otherwise.
+    pub fn area(&self) -> i128 {
+        self.double_area() / 2
+    }
+
+    /// The number of lattice points on the boundary.
+    /// If all edges are horizontal or vertical, this is the length of the boundary.
+    pub fn boundary_points(&self) -> i128 {
+        self.edges()
+            .map(|(a, b)| gcd(a.x.abs_diff(b.x), a.y.abs_diff(b.y)) as i128)
+            .sum()
+    }
+
+    /// The number of lattice points strictly inside the polygon, using Pick's theorem.
+    /// Polygons without an area, such as those with fewer than three vertices, have no inside.
+    pub fn interior_points(&self) -> i128 {
+        let double_area = self.double_area();
+        if double_area == 0 {
+            return 0;
+        }
+
+        (double_area - self.boundary_points()) / 2 + 1
+    }
+
+    /// Whether the point lies on one of the edges of the polygon.
+    pub fn on_boundary(&self, point: Point2) -> bool {
+        self.edges().any(|(a, b)| {
+            let cross = (b.x - a.x) as i128 * (point.y - a.y) as i128
+                - (b.y - a.y) as i128 * (point.x - a.x) as i128;
+
+            cross == 0
+                && point.x >= a.x.min(b.x)
+                && point.x <= a.x.max(b.x)
+                && point.y >= a.y.min(b.y)
+                && point.y <= a.y.max(b.y)
+        })
+    }
+
+    /// Whether the point lies inside the polygon or on its boundary.
+    pub fn contains(&self, point: Point2) -> bool {
+        if self.on_boundary(point) {
+            return true;
+        }
+
+        // count the edges crossed by a ray from the point towards positive x.
+        let crossings = self
+            .edges()
+            .filter(|(a, b)| (a.y > point.y) != (b.y > point.y))
+            .filter(|(a, b)| {
+                let cross = (b.x - a.x) as i128 * (point.y - a.y) as i128
+                    - (b.y - a.y) as i128 * (point.x - a.x) as i128;
+
+                // the edge crosses the ray to the right of the point, if the point lies on the left of an upward
+                // edge, or on the right of a downward edge.
+                (cross > 0) == (b.y > a.y)
+            })
+            .count();
+
+        crossings % 2 == 1
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Polygon;
+    use crate::point::{Direction, Point2};
+
+    #[test]
+    fn measures_rectilinear_polygons() {
+        // L shape, counterclockwise.
+        let polygon = Polygon::new(vec![
+            Point2::new(0, 0),
+            Point2::new(0, 4),
+            Point2::new(6, 4),
+            Point2::new(6, 2),
+            Point2::new(2, 2),
+            Point2::new(2, 0),
+        ]);
+
+        assert_eq!(polygon.area(), 16);
+        assert_eq!(polygon.boundary_points(), 20);
+        assert_eq!(polygon.interior_points(), 7);
+    }
+
+    #[test]
+    fn measures_sloped_polygons() {
+        let triangle = Polygon::new(vec![
+            Point2::new(0, 0),
+            Point2::new(3, 0),
+            Point2::new(0, 3),
+        ]);
+
+        assert_eq!(triangle.double_area(), 9);
+        assert_eq!(triangle.area(), 4);
+        assert_eq!(triangle.boundary_points(), 9);
+        assert_eq!(triangle.interior_points(), 1);
+    }
+
+    #[test]
+    fn measures_degenerate_polygons() {
+        let line = Polygon::new(vec![Point2::new(0, 0), Point2::new(3, 0)]);
+        assert_eq!(line.area(), 0);
+        assert_eq!(line.boundary_points(), 6);
+        assert_eq!(line.interior_points(), 0);
+
+        let collinear = Polygon::new(vec![
+            Point2::new(0, 0),
+            Point2::new(3, 0),
+            Point2::new(1, 0),
+        ]);
+        assert_eq!(collinear.interior_points(), 0);
+
+        let point = Polygon::new(vec![Point2::new(2, 2)]);
+        assert_eq!(point.interior_points(), 0);
+        assert_eq!(Polygon::new(vec![]).interior_points(), 0);
+    }
+
+    #[test]
+    fn traces_steps() {
+        // example of day 18, 2023.
+        let steps = [
+            (Direction::East, 6),
+            (Direction::South, 5),
+            (Direction::West, 2),
+            (Direction::South, 2),
+            (Direction::East, 2),
+            (Direction::South, 2),
+            (Direction::West, 5),
+            (Direction::North, 2),
+            (Direction::West, 1),
+            (Direction::North, 2),
+            (Direction::East, 2),
+            (Direction::North, 3),
+            (Direction::West, 2),
+            (Direction::North, 2),
+        ];
+        let polygon = Polygon::from_steps(steps);
+
+        assert_eq!(polygon.vertices().len(), 14);
+        assert_eq!(polygon.area(), 42);
+        assert_eq!(polygon.boundary_points(), 38);
+        assert_eq!(polygon.interior_points() + polygon.boundary_points(), 62);
+    }
+
+    #[test]
+    fn contains_points() {
+        let polygon = Polygon::new(vec![
+            Point2::new(0, 0),
+            Point2::new(4, 0),
+            Point2::new(4, 4),
+            Point2::new(2, 2),
+            Point2::new(0, 4),
+        ]);
+
+        assert!(polygon.contains(Point2::new(1, 1)));
+        assert!(polygon.contains(Point2::new(1, 2)));
+        assert!(!polygon.contains(Point2::new(2, 3)));
+        assert!(!polygon.contains(Point2::new(-1, 0)));
+        assert!(!polygon.contains(Point2::new(5, 2)));
+
+        assert!(polygon.on_boundary(Point2::new(3, 3)));
+        assert!(polygon.on_boundary(Point2::new(4, 2)));
+        assert!(!polygon.on_boundary(Point2::new(3, 2)));
+        assert!(polygon.contains(Point2::new(3, 3)));
+    }
+}
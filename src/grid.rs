@@ -0,0 +1,455 @@
+//! A rectangular grid of cells, as found in most puzzle inputs.
+//!
+//! Cells are addressed by `(x, y)`, with `x` counting columns from the left and `y` counting rows from the top.
+//!
+//! ```
+//! # use advent_of_code::grid::Grid;
+//! let grid: Grid<char> = "#..\n.#.".parse().unwrap();
+//! assert_eq!(grid.width(), 3);
+//! assert_eq!(grid[(1, 1)], '#');
+//! assert_eq!(grid.get(3, 0), None);
+//! assert_eq!(grid.find(&'#'), Some((0, 0)));
+//! ```
+use std::error::Error;
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use crate::point::Point2;
+
+/// Offsets of the four orthogonal neighbours of a cell: up, right, down, left.
+const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of all eight neighbours of a cell, clockwise starting at the top left.
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid of the given size with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Creates a grid from its rows. Returns [`None`] if the rows are not all of the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses a grid with one row per line of `input`, converting every character with `f`.
+    pub fn parse_with(input: &str, mut f: impl FnMut(char) -> T) -> Result<Self, GridFromStrError> {
+        let rows = input
+            .lines()
+            .map(|line| line.chars().map(&mut f).collect())
+            .collect();
+
+        Self::from_rows(rows).ok_or(GridFromStrError)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `(x, y)` lies within the grid.
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    /// Returns the cell at `(x, y)`, or [`None`] if it lies outside the grid.
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        self.contains(x, y)
+            .then(|| &self.cells[y as usize * self.width + x as usize])
+    }
+
+    /// Returns the cell at `(x, y)` mutably, or [`None`] if it lies outside the grid.
+    pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
+        if !self.contains(x, y) {
+            return None;
+        }
+
+        Some(&mut self.cells[y as usize * self.width + x as usize])
+    }
+
+    /// Returns the cell at `(x, y)` of a grid that repeats infinitely in every direction.
+    ///
+    /// # Panics
+    /// Panics if the grid is empty.
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        #[allow(clippy::cast_possible_wrap)]
+        let (width, height) = (self.width as isize, self.height as isize);
+        &self[(x.rem_euclid(width) as usize, y.rem_euclid(height) as usize)]
+    }
+
+    /// Iterates over the orthogonal neighbours of `(x, y)` that lie within the grid.
+    pub fn neighbours(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offset_all(x, y, &NEIGHBOURS_4)
+    }
+
+    /// Iterates over the orthogonal and diagonal neighbours of `(x, y)` that lie within the grid.
+    pub fn neighbours_diagonal(
+        &self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offset_all(x, y, &NEIGHBOURS_8)
+    }
+
+    fn offset_all<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let nx = x.checked_add_signed(*dx)?;
+            let ny = y.checked_add_signed(*dy)?;
+            (nx < self.width && ny < self.height).then_some((nx, ny))
+        })
+    }
+
+    /// Iterates over the rows of the grid, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // NOTE: `chunks` panics on a chunk size of 0, which an empty grid would have.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Iterates over the cells of column `x`, top to bottom.
+    ///
+    /// # Panics
+    /// Panics if `x` is outside of the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {x} is outside of the {}x{} grid",
+            self.width,
+            self.height
+        );
+
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Iterates over the columns of the grid, left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Iterates over all cells along with their position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| ((i % self.width, i / self.width), cell))
+    }
+
+    /// Iterates mutably over all cells along with their position, row by row.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        let width = self.width;
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    /// Returns the position of the first cell equal to `value`, row by row.
+    pub fn find(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.position(|cell| cell == value)
+    }
+
+    /// Returns the position of the first cell that matches `predicate`, row by row.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    /// Swaps the cells at two positions.
+    pub fn swap(&mut self, a: (usize, usize), b: (usize, usize)) {
+        let (a, b) = (self.offset(a), self.offset(b));
+        self.cells.swap(a, b);
+    }
+
+    /// Returns a grid with rows and columns swapped.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |x, y| (y, x))
+    }
+
+    /// Returns the grid rotated by 90 degrees clockwise.
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |x, y| (y, self.height - 1 - x))
+    }
+
+    /// Returns the grid rotated by 90 degrees counterclockwise.
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |x, y| (self.width - 1 - y, x))
+    }
+
+    /// Builds a grid of the given size, taking each cell `(x, y)` from the position `source(x, y)` of this grid.
+    fn remap(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| self[source(x, y)].clone())
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    fn offset(&self, (x, y): (usize, usize)) -> usize {
+        assert!(
+            x < self.width && y < self.height,
+            "position ({x}, {y}) is outside of the {}x{} grid",
+            self.width,
+            self.height
+        );
+
+        y * self.width + x
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &Self::Output {
+        &self.cells[self.offset(position)]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut Self::Output {
+        let offset = self.offset(position);
+        &mut self.cells[offset]
+    }
+}
+
+impl<T> Index<Point2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point2) -> &Self::Output {
+        self.get(point.x, point.y)
+            .expect("point is outside of the grid")
+    }
+}
+
+impl<T> IndexMut<Point2> for Grid<T> {
+    fn index_mut(&mut self, point: Point2) -> &mut Self::Output {
+        self.get_mut(point.x, point.y)
+            .expect("point is outside of the grid")
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = GridFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, |c| c)
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// An error which can be returned when parsing a [`Grid`].
+#[derive(Debug)]
+pub struct GridFromStrError;
+
+impl Error for GridFromStrError {}
+
+impl Display for GridFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting all lines of a grid to be of the same length")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Grid;
+    use crate::point::Point2;
+
+    fn grid() -> Grid<char> {
+        "abc\ndef".parse().unwrap()
+    }
+
+    #[test]
+    fn parses_grids() {
+        let grid = grid();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[(2, 1)], 'f');
+
+        let digits = Grid::parse_with("12\n34", |c| c.to_digit(10).unwrap()).unwrap();
+        assert_eq!(digits[(0, 1)], 3);
+
+        assert!("ab\nc".parse::<Grid<char>>().is_err());
+        assert_eq!("".parse::<Grid<char>>().unwrap().width(), 0);
+    }
+
+    #[test]
+    fn checks_bounds() {
+        let grid = grid();
+        assert_eq!(grid.get(0, 0), Some(&'a'));
+        assert_eq!(grid.get(-1, 0), None);
+        assert_eq!(grid.get(3, 1), None);
+        assert_eq!(grid.get(2, 2), None);
+        assert_eq!(*grid.get_wrapping(-1, 2), 'c');
+        assert_eq!(*grid.get_wrapping(7, -1), 'e');
+    }
+
+    #[test]
+    fn indexes_with_points() {
+        let mut grid = grid();
+        assert_eq!(grid[Point2::new(1, 1)], 'e');
+
+        grid[Point2::new(0, 1)] = 'x';
+        assert_eq!(grid.to_string(), "abc\nxef");
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_when_indexing_out_of_bounds() {
+        let _ = grid()[(3, 0)];
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is outside of the 3x2 grid")]
+    fn panics_when_reading_columns_out_of_bounds() {
+        let _ = grid().column(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "point is outside of the grid")]
+    fn panics_when_reading_points_out_of_bounds() {
+        let _ = grid()[Point2::new(-1, 0)];
+    }
+
+    #[test]
+    #[should_panic(expected = "point is outside of the grid")]
+    fn panics_when_writing_points_out_of_bounds() {
+        grid()[Point2::new(-1, 0)] = 'x';
+    }
+
+    #[test]
+    fn finds_neighbours() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbours(0, 0).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours(1, 1).count(), 3);
+        assert_eq!(
+            grid.neighbours_diagonal(1, 0).collect::<Vec<_>>(),
+            vec![(2, 0), (2, 1), (1, 1), (0, 1), (0, 0)]
+        );
+    }
+
+    #[test]
+    fn iterates_rows_and_columns() {
+        let grid = grid();
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&['a', 'b', 'c'], &['d', 'e', 'f']]
+        );
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["ad", "be", "cf"]
+        );
+    }
+
+    #[test]
+    fn transforms_grids() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    }
+
+    #[test]
+    fn finds_and_updates_cells() {
+        let mut grid = grid();
+        assert_eq!(grid.find(&'e'), Some((1, 1)));
+        assert_eq!(grid.find(&'z'), None);
+
+        grid.swap((0, 0), (2, 1));
+        *grid.get_mut(1, 0).unwrap() = 'x';
+        assert_eq!(grid.to_string(), "fxc\ndea");
+        assert_eq!(grid.position(|c| *c > 'e'), Some((0, 0)));
+    }
+}
@@ -0,0 +1,340 @@
+//! Sets of values made up of ranges, and maps that shift ranges of values to other ranges.
+//!
+//! ```
+//! # use advent_of_code::intervals::{RangeMap, RangeSet};
+//! let set = RangeSet::from_ranges([1..5, 3..8, 10..12]);
+//! assert_eq!(set.ranges(), &[1..8, 10..12]);
+//! assert_eq!(set.difference(&RangeSet::from(4..11)).ranges(), &[1..4, 11..12]);
+//!
+//! let mut map = RangeMap::new();
+//! map.insert(5..10, 50);
+//! assert_eq!(map.apply_set(&set).ranges(), &[1..5, 10..12, 50..53]);
+//! ```
+use std::ops::{Add, Range, Sub};
+
+use num::Zero;
+
+/// A set of values, stored as sorted, non-empty and non-overlapping half-open ranges.
+/// Adjacent ranges are merged, so two sets with the same values are always equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Copy + Ord> RangeSet<T> {
+    pub fn new() -> Self {
+        Self { ranges: vec![] }
+    }
+
+    /// Creates a set of all values in any of `ranges`, which may overlap.
+    pub fn from_ranges(ranges: impl IntoIterator<Item = Range<T>>) -> Self {
+        let mut ranges: Vec<Range<T>> = ranges.into_iter().filter(|r| r.start < r.end).collect();
+        ranges.sort_unstable_by_key(|r| r.start);
+
+        let mut merged: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+
+        Self { ranges: merged }
+    }
+
+    /// The ranges of the set, in ascending order.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        // NOTE: ranges are sorted, find the last one starting at or before the value.
+        let index = self.ranges.partition_point(|r| r.start <= value);
+        index > 0 && value < self.ranges[index - 1].end
+    }
+
+    /// The smallest value of the set.
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    /// Adds all values of `range` to the set.
+    pub fn insert(&mut self, range: Range<T>) {
+        *self = Self::from_ranges(self.ranges.iter().cloned().chain([range]));
+    }
+
+    /// Values that are in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        Self::from_ranges(self.ranges.iter().chain(&other.ranges).cloned())
+    }
+
+    /// Values that are in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+
+            if start < end {
+                ranges.push(start..end);
+            }
+
+            // the range that ends first can not overlap with any later range of the other set.
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// Values that are in this set, but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let mut j = 0;
+
+        for range in &self.ranges {
+            let mut start = range.start;
+
+            // skip ranges of the other set that end before this one starts.
+            while other.ranges.get(j).is_some_and(|r| r.end <= start) {
+                j += 1;
+            }
+
+            let mut k = j;
+
+            while let Some(cut) = other.ranges.get(k).filter(|r| r.start < range.end) {
+                if start < cut.start {
+                    ranges.push(start..cut.start);
+                }
+
+                start = start.max(cut.end);
+                k += 1;
+            }
+
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// Splits the set into the values below `point` and the values at or above it.
+    pub fn split_at(&self, point: T) -> (Self, Self) {
+        let mut below = vec![];
+        let mut above = vec![];
+
+        for range in &self.ranges {
+            if range.end <= point {
+                below.push(range.clone());
+            } else if range.start >= point {
+                above.push(range.clone());
+            } else {
+                below.push(range.start..point);
+                above.push(point..range.end);
+            }
+        }
+
+        (Self { ranges: below }, Self { ranges: above })
+    }
+
+    /// Moves every value of the set by `offset`. For signed values, a negative offset moves them down.
+    pub fn shift(&self, offset: T) -> Self
+    where
+        T: Add<Output = T>,
+    {
+        Self {
+            ranges: self
+                .ranges
+                .iter()
+                .map(|r| r.start + offset..r.end + offset)
+                .collect(),
+        }
+    }
+
+    /// The number of values in the set.
+    pub fn len(&self) -> T
+    where
+        T: Sub<Output = T> + Zero,
+    {
+        self.ranges
+            .iter()
+            .fold(T::zero(), |sum, r| sum + (r.end - r.start))
+    }
+}
+
+impl<T: Copy + Ord> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + Ord> From<Range<T>> for RangeSet<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::from_ranges([range])
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        Self::from_ranges(iter)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A piecewise-linear map: each piece moves a range of source values to a range of the same length starting at its target.
+/// Values outside of all pieces map to themselves. Where pieces overlap, the one inserted first applies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeMap<T> {
+    pieces: Vec<(Range<T>, T)>,
+}
+
+impl<T> RangeMap<T>
+where
+    T: Copy + Ord + Add<Output = T> + Sub<Output = T>,
+{
+    pub fn new() -> Self {
+        Self { pieces: vec![] }
+    }
+
+    /// Adds a piece that maps `source` to the range of the same length starting at `target`.
+    pub fn insert(&mut self, source: Range<T>, target: T) {
+        self.pieces.push((source, target));
+    }
+
+    /// Maps a single value.
+    pub fn apply(&self, value: T) -> T {
+        self.pieces
+            .iter()
+            .find(|(source, _)| source.contains(&value))
+            .map_or(value, |(source, target)| *target + (value - source.start))
+    }
+
+    /// Maps every value of a set.
+    pub fn apply_set(&self, set: &RangeSet<T>) -> RangeSet<T> {
+        let mut unmapped = set.clone();
+        let mut mapped = vec![];
+
+        for (source, target) in &self.pieces {
+            let pieces = RangeSet::from(source.clone());
+
+            for range in unmapped.intersection(&pieces).ranges() {
+                let start = *target + (range.start - source.start);
+                mapped.push(start..start + (range.end - range.start));
+            }
+
+            unmapped = unmapped.difference(&pieces);
+        }
+
+        RangeSet::from_ranges(mapped.into_iter().chain(unmapped.ranges))
+    }
+}
+
+impl<T> Default for RangeMap<T>
+where
+    T: Copy + Ord + Add<Output = T> + Sub<Output = T>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{RangeMap, RangeSet};
+
+    #[test]
+    fn normalizes_ranges() {
+        let set = RangeSet::from_ranges([8..10, 1..3, 3..5, 2..4, 6..6]);
+        assert_eq!(set.ranges(), &[1..5, 8..10]);
+        assert_eq!(set, [1..5, 8..10].into_iter().collect());
+        assert_eq!(set.len(), 6);
+        assert_eq!(set.min(), Some(1));
+
+        assert!(set.contains(1));
+        assert!(set.contains(9));
+        assert!(!set.contains(5));
+        assert!(!set.contains(0));
+
+        assert!(RangeSet::<u32>::new().is_empty());
+        assert!(RangeSet::from(3..3).is_empty());
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a = RangeSet::from_ranges([0..10, 20..30]);
+        let b = RangeSet::from_ranges([5..25, 28..40]);
+
+        assert_eq!(a.union(&b), RangeSet::from(0..40));
+        assert_eq!(a.intersection(&b).ranges(), &[5..10, 20..25, 28..30]);
+        assert_eq!(a.difference(&b).ranges(), &[0..5, 25..28]);
+        assert_eq!(b.difference(&a).ranges(), &[10..20, 30..40]);
+        assert!(a.difference(&a).is_empty());
+
+        let mut c = a.clone();
+        c.insert(10..20);
+        assert_eq!(c, RangeSet::from(0..30));
+    }
+
+    #[test]
+    fn splits_and_shifts_sets() {
+        let set = RangeSet::from_ranges([0..10, 20..30]);
+
+        let (below, above) = set.split_at(25);
+        assert_eq!(below.ranges(), &[0..10, 20..25]);
+        assert_eq!(above, RangeSet::from(25..30));
+
+        let (below, above) = set.split_at(10);
+        assert_eq!(below, RangeSet::from(0..10));
+        assert_eq!(above, RangeSet::from(20..30));
+
+        assert_eq!(RangeSet::from(-5..5).shift(-10), RangeSet::from(-15..-5));
+    }
+
+    #[test]
+    fn maps_ranges() {
+        // first map of the example of day 5, 2023.
+        let mut map = RangeMap::new();
+        map.insert(98..100, 50);
+        map.insert(50..98, 52);
+
+        assert_eq!(map.apply(79), 81);
+        assert_eq!(map.apply(99), 51);
+        assert_eq!(map.apply(10), 10);
+
+        let set = RangeSet::from_ranges([79..93, 95..101]);
+        assert_eq!(map.apply_set(&set).ranges(), &[50..52, 81..95, 97..101]);
+    }
+
+    #[test]
+    fn applies_first_overlapping_piece() {
+        let mut map = RangeMap::new();
+        map.insert(0..10, 100);
+        map.insert(5..15, 200);
+
+        assert_eq!(map.apply(7), 107);
+        assert_eq!(map.apply(12), 207);
+        assert_eq!(
+            map.apply_set(&RangeSet::from(0..15)).ranges(),
+            &[100..110, 205..210]
+        );
+
+        // empty pieces map nothing.
+        let mut map = RangeMap::new();
+        map.insert(5..5, 100);
+        assert_eq!(map.apply_set(&RangeSet::from(0..10)), RangeSet::from(0..10));
+    }
+}
@@ -1,3 +1,8 @@
+pub mod geometry;
+pub mod grid;
+pub mod intervals;
+pub mod point;
+pub mod search;
 pub mod template;
 
 use num::integer::lcm;
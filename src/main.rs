@@ -1,14 +1,25 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, download, examples, read, scaffold, solve, time, verify,
+};
 use args::{parse, AppArguments};
 
+use advent_of_code::template::PuzzleId;
+
 #[cfg(feature = "today")]
 use advent_of_code::template::Day;
 #[cfg(feature = "today")]
 use std::process;
 
+/// Solutions found in `src/bin`, registered so `all`, `time` and `verify` can call them in-process.
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::commands::{scaffold, time};
+    use advent_of_code::template::{Day, InputSource, Year};
     use std::process;
+    use std::time::Duration;
 
     pub enum AppArguments {
         Download {
@@ -17,61 +28,143 @@ mod args {
         Read {
             day: Day,
         },
+        Examples {
+            day: Day,
+            overwrite: bool,
+        },
         Scaffold {
             day: Day,
             download: bool,
+            template: String,
         },
         Solve {
             day: Day,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            source: InputSource,
         },
         All {
             release: bool,
+            jobs: usize,
+            timeout: Option<Duration>,
         },
         Time {
             all: bool,
             day: Option<Day>,
             store: bool,
+            stats: bool,
+            isolated: bool,
+            threshold: f64,
+            fail_on_regression: bool,
+            timeout: Option<Duration>,
+            budget: Option<Duration>,
+            fail_over_budget: bool,
+        },
+        TimeHistory {
+            day: Day,
+        },
+        Verify {
+            day: Option<Day>,
+            release: bool,
         },
         #[cfg(feature = "today")]
         Today,
     }
 
-    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
+    /// Parse the command and the year it applies to. The year defaults to `AOC_YEAR` when `--year` is not passed.
+    pub fn parse() -> Result<(Year, AppArguments), Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let subcommand = args.subcommand()?;
+
+        let year = match args.opt_value_from_str("--year")? {
+            Some(year) => year,
+            None => Year::from_env().ok_or(
+                "No year specified. Pass `--year <year>` or set AOC_YEAR in .cargo/config.toml.",
+            )?,
+        };
+
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
                 release: args.contains("--release"),
+                jobs: args.opt_value_from_str(["-j", "--jobs"])?.unwrap_or(1),
+                timeout: args.opt_value_from_fn("--timeout", parse_seconds)?,
             },
-            Some("time") => {
-                let all = args.contains("--all");
-                let store = args.contains("--store");
+            Some("time") => match args.opt_value_from_str("--history")? {
+                Some(day) => AppArguments::TimeHistory { day },
+                None => {
+                    let all = args.contains("--all");
+                    let store = args.contains("--store");
+                    let stats = args.contains("--stats");
+                    let isolated = args.contains("--isolated");
+                    let fail_on_regression = args.contains("--fail-on-regression");
+                    let fail_over_budget = args.contains("--fail-over-budget");
+                    let timeout = args.opt_value_from_fn("--timeout", parse_seconds)?;
+                    let budget = args.opt_value_from_fn("--budget", parse_seconds)?;
+                    let threshold = args
+                        .opt_value_from_str("--threshold")?
+                        .unwrap_or(time::DEFAULT_REGRESSION_THRESHOLD);
 
-                AppArguments::Time {
-                    all,
-                    day: args.opt_free_from_str()?,
-                    store,
+                    AppArguments::Time {
+                        all,
+                        day: args.opt_free_from_str()?,
+                        store,
+                        stats,
+                        isolated,
+                        threshold,
+                        fail_on_regression,
+                        timeout,
+                        budget,
+                        fail_over_budget,
+                    }
                 }
-            }
+            },
+            Some("verify") => AppArguments::Verify {
+                release: args.contains("--release"),
+                day: args.opt_free_from_str()?,
+            },
             Some("download") => AppArguments::Download {
                 day: args.free_from_str()?,
             },
             Some("read") => AppArguments::Read {
                 day: args.free_from_str()?,
             },
-            Some("scaffold") => AppArguments::Scaffold {
+            Some("examples") => AppArguments::Examples {
                 day: args.free_from_str()?,
-                download: args.contains("--download"),
+                overwrite: args.contains("--overwrite"),
             },
-            Some("solve") => AppArguments::Solve {
+            Some("scaffold") => AppArguments::Scaffold {
                 day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
+                download: args.contains("--download"),
+                template: args
+                    .opt_value_from_str("--template")?
+                    .unwrap_or_else(|| scaffold::DEFAULT_TEMPLATE_NAME.into()),
             },
+            Some("solve") => {
+                let day = args.free_from_str()?;
+                let release = args.contains("--release");
+                let submit = args.opt_value_from_str("--submit")?;
+                let dhat = args.contains("--dhat");
+
+                let source = match args.opt_value_from_str::<_, String>("--input")? {
+                    Some(path) if path == "-" => InputSource::Stdin,
+                    Some(path) => InputSource::Path(path.into()),
+                    // the number of the example is optional, so it is parsed after all other arguments.
+                    None if args.contains("--example") => {
+                        InputSource::Example(args.opt_free_from_str()?)
+                    }
+                    None => InputSource::Puzzle,
+                };
+
+                AppArguments::Solve {
+                    day,
+                    release,
+                    dhat,
+                    submit,
+                    source,
+                }
+            }
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
             Some(x) => {
@@ -89,7 +182,13 @@ mod args {
             eprintln!("Warning: unknown argument(s): {remaining:?}.");
         }
 
-        Ok(app_args)
+        Ok((year, app_args))
+    }
+
+    /// Parse a duration given in seconds, e.g. `--timeout 2.5`.
+    fn parse_seconds(s: &str) -> Result<Duration, String> {
+        let seconds: f64 = s.parse().map_err(|_| format!("{s} is not a number."))?;
+        Duration::try_from_secs_f64(seconds).map_err(|e| e.to_string())
     }
 }
 
@@ -99,30 +198,79 @@ fn main() {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
-        Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day, download } => {
-                scaffold::handle(day);
+        Ok((year, args)) => match args {
+            AppArguments::All {
+                release,
+                jobs,
+                timeout,
+            } => {
+                all::handle(&solutions::registry(), year, release, jobs, timeout);
+            }
+            AppArguments::Time {
+                day,
+                all,
+                store,
+                stats,
+                isolated,
+                threshold,
+                fail_on_regression,
+                timeout,
+                budget,
+                fail_over_budget,
+            } => {
+                time::handle(
+                    &solutions::registry(),
+                    year,
+                    day,
+                    all,
+                    store,
+                    stats,
+                    isolated,
+                    threshold,
+                    fail_on_regression,
+                    timeout,
+                    budget,
+                    fail_over_budget,
+                );
+            }
+            AppArguments::TimeHistory { day } => {
+                time::handle_history(PuzzleId::new(year, day));
+            }
+            AppArguments::Verify { day, release } => {
+                verify::handle(&solutions::registry(), year, day, release);
+            }
+            AppArguments::Download { day } => download::handle(PuzzleId::new(year, day)),
+            AppArguments::Read { day } => read::handle(PuzzleId::new(year, day)),
+            AppArguments::Examples { day, overwrite } => {
+                examples::handle(PuzzleId::new(year, day), overwrite);
+            }
+            AppArguments::Scaffold {
+                day,
+                download,
+                template,
+            } => {
+                let puzzle = PuzzleId::new(year, day);
+                // download first, so the template can use the title and example answers of the puzzle.
                 if download {
-                    download::handle(day);
+                    download::handle(puzzle);
                 }
+                scaffold::handle(puzzle, &template);
             }
             AppArguments::Solve {
                 day,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                source,
+            } => solve::handle(PuzzleId::new(year, day), release, dhat, submit, &source),
             #[cfg(feature = "today")]
             AppArguments::Today => {
                 match Day::today() {
                     Some(day) => {
-                        scaffold::handle(day);
-                        download::handle(day);
-                        read::handle(day)
+                        let puzzle = PuzzleId::new(year, day);
+                        download::handle(puzzle);
+                        scaffold::handle(puzzle, scaffold::DEFAULT_TEMPLATE_NAME);
+                        read::handle(puzzle)
                     }
                     None => {
                         eprintln!(
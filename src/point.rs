@@ -0,0 +1,295 @@
+//! Points on a grid or in space, and the four directions to move between grid cells in.
+//!
+//! Grid points use the same orientation as [`Grid`](crate::grid::Grid): `x` grows to the east and `y` grows to the south.
+//!
+//! ```
+//! # use advent_of_code::point::{Direction, Point2};
+//! let point = Point2::new(2, 3) + Direction::North;
+//! assert_eq!(point, Point2::new(2, 2));
+//! assert_eq!(Direction::North.turn_right(), Direction::East);
+//! assert_eq!(point.manhattan_distance(Point2::ORIGIN), 4);
+//! assert_eq!(Point2::new(-1, 0).to_index(), None);
+//! ```
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// One of the four directions to move between grid cells in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// All directions, clockwise starting at north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// The offset of a single step in this direction.
+    pub fn offset(self) -> Point2 {
+        match self {
+            Direction::North => Point2::new(0, -1),
+            Direction::East => Point2::new(1, 0),
+            Direction::South => Point2::new(0, 1),
+            Direction::West => Point2::new(-1, 0),
+        }
+    }
+
+    /// The direction after turning 90 degrees counterclockwise.
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    /// The direction after turning 90 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A point on a grid.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2 {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2::new(0, 0);
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The four orthogonal neighbours of the point, clockwise starting at north.
+    pub fn neighbours(self) -> impl Iterator<Item = Point2> {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| self + direction)
+    }
+
+    /// Converts the point to a grid index `(x, y)`. Returns [`None`] if a coordinate is negative.
+    pub fn to_index(self) -> Option<(usize, usize)> {
+        Some((usize::try_from(self.x).ok()?, usize::try_from(self.y).ok()?))
+    }
+
+    /// Converts a grid index `(x, y)` to a point. Returns [`None`] if a coordinate does not fit.
+    pub fn from_index((x, y): (usize, usize)) -> Option<Self> {
+        Some(Self::new(
+            isize::try_from(x).ok()?,
+            isize::try_from(y).ok()?,
+        ))
+    }
+}
+
+impl Add for Point2 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Add<Direction> for Point2 {
+    type Output = Self;
+
+    fn add(self, rhs: Direction) -> Self::Output {
+        self + rhs.offset()
+    }
+}
+
+impl AddAssign for Point2 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl AddAssign<Direction> for Point2 {
+    fn add_assign(&mut self, rhs: Direction) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point2 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Point2 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<isize> for Point2 {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for Point2 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A point in space.
+/// Coordinates are 64 bit wide, so that the large positions of e.g. day 24 of 2023 fit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3::new(0, 0, 0);
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+}
+
+impl Add for Point3 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl AddAssign for Point3 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl SubAssign for Point3 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i64> for Point3 {
+    type Output = Self;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl Neg for Point3 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Direction, Point2, Point3};
+
+    #[test]
+    fn turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+            assert_eq!(
+                direction.offset() + direction.opposite().offset(),
+                Point2::ORIGIN
+            );
+        }
+
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+    }
+
+    #[test]
+    fn moves_points() {
+        let mut point = Point2::new(3, 4);
+        point += Direction::South;
+        point -= Point2::new(1, 1);
+        assert_eq!(point, Point2::new(2, 4));
+        assert_eq!(point * 2 - point, point);
+        assert_eq!(-point, Point2::new(-2, -4));
+        assert_eq!(point.manhattan_distance(Point2::new(-1, 5)), 4);
+        assert_eq!(
+            Point2::ORIGIN.neighbours().collect::<Vec<_>>(),
+            vec![
+                Point2::new(0, -1),
+                Point2::new(1, 0),
+                Point2::new(0, 1),
+                Point2::new(-1, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn converts_to_indices() {
+        assert_eq!(Point2::new(2, 7).to_index(), Some((2, 7)));
+        assert_eq!(Point2::new(2, -1).to_index(), None);
+        assert_eq!(Point2::from_index((2, 7)), Some(Point2::new(2, 7)));
+        assert_eq!(Point2::from_index((usize::MAX, 0)), None);
+    }
+
+    #[test]
+    fn moves_points_in_space() {
+        let point = Point3::new(1, -2, 3) + Point3::new(1, 1, 1) * 2;
+        assert_eq!(point, Point3::new(3, 0, 5));
+        assert_eq!(point - point, Point3::ORIGIN);
+        assert_eq!(point.manhattan_distance(-point), 16);
+    }
+}
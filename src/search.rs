@@ -0,0 +1,459 @@
+//! Graph searches over any state type, given a closure that returns the successors of a state.
+//!
+//! All searches accept several start states, so that puzzles with more than one start need no special handling.
+//! [`dijkstra`], [`astar`], [`bfs`] and [`dfs`] also take a predicate for the target, stop at the first target they
+//! reach and return the [`Path`] to it. [`dijkstra_all`] and [`bfs_all`] instead return the cost of every reachable state.
+//!
+//! ```
+//! # use advent_of_code::search::dijkstra;
+//! // walk the number line, a step forward costs 1 and doubling costs 3.
+//! let path = dijkstra([1], |n: &u32| [(n + 1, 1), (n * 2, 3)], |n| *n == 10).unwrap();
+//! assert_eq!(path.cost, 7);
+//! assert_eq!(path.states, vec![1, 2, 3, 4, 5, 10]);
+//! ```
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use num::Zero;
+
+/// A path found by a search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    /// All states along the path, from the start to the target.
+    pub states: Vec<S>,
+    /// Cost of reaching the target. For unweighted searches, the number of steps.
+    pub cost: C,
+}
+
+impl<S, C> Path<S, C> {
+    /// The state the path ends in.
+    pub fn target(&self) -> &S {
+        self.states.last().unwrap()
+    }
+}
+
+/// How a state was reached in a search.
+struct Visit<C> {
+    parent: Option<usize>,
+    cost: C,
+}
+
+/// States a search has seen, indexed in the order they were first reached.
+struct Visited<S, C> {
+    states: Vec<S>,
+    indices: HashMap<S, usize>,
+    visits: Vec<Visit<C>>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Visited<S, C> {
+    fn new() -> Self {
+        Self {
+            states: vec![],
+            indices: HashMap::new(),
+            visits: vec![],
+        }
+    }
+
+    /// Records how a state was reached, returning its index.
+    fn insert(&mut self, state: S, visit: Visit<C>) -> usize {
+        if let Some(&index) = self.indices.get(&state) {
+            self.visits[index] = visit;
+            return index;
+        }
+
+        let index = self.states.len();
+        self.indices.insert(state.clone(), index);
+        self.states.push(state);
+        self.visits.push(visit);
+        index
+    }
+
+    fn get(&self, state: &S) -> Option<&Visit<C>> {
+        self.indices.get(state).map(|&index| &self.visits[index])
+    }
+
+    /// Follows the parents of a state back to the start.
+    fn path_to(&self, index: usize) -> Path<S, C> {
+        let mut states = vec![self.states[index].clone()];
+        let mut current = index;
+
+        while let Some(parent) = self.visits[current].parent {
+            states.push(self.states[parent].clone());
+            current = parent;
+        }
+
+        states.reverse();
+
+        Path {
+            states,
+            cost: self.visits[index].cost,
+        }
+    }
+
+    fn into_costs(self) -> HashMap<S, C> {
+        self.states
+            .into_iter()
+            .zip(self.visits)
+            .map(|(state, visit)| (state, visit.cost))
+            .collect()
+    }
+}
+
+/// Finds the cheapest path from any of `starts` to a state that matches `is_target`.
+/// `successors` returns the states reachable from a state along with the cost of getting there, which must not be negative.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_target: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, |_| C::zero(), is_target)
+}
+
+/// Finds the cost of the cheapest path from any of `starts` to every state reachable from them.
+pub fn dijkstra_all<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+) -> HashMap<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let (visited, _) = run_astar(starts, successors, |_| C::zero(), |_| false);
+    visited.into_costs()
+}
+
+/// Finds the cheapest path like [`dijkstra`], visiting states in order of their cost plus `heuristic`.
+/// The heuristic estimates the remaining cost to a target. It must never overestimate it, otherwise the path
+/// found might not be the cheapest.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_target: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let (visited, target) = run_astar(starts, successors, heuristic, is_target);
+    target.map(|index| visited.path_to(index))
+}
+
+fn run_astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_target: impl FnMut(&S) -> bool,
+) -> (Visited<S, C>, Option<usize>)
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut visited = Visited::new();
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        let estimate = heuristic(&start);
+        let visit = Visit {
+            parent: None,
+            cost: C::zero(),
+        };
+        heap.push(Reverse((estimate, C::zero(), visited.insert(start, visit))));
+    }
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        // NOTE: a state is pushed again whenever a cheaper path to it is found, skip the outdated entries.
+        if cost > visited.visits[index].cost {
+            continue;
+        }
+
+        let state = visited.states[index].clone();
+
+        if is_target(&state) {
+            return (visited, Some(index));
+        }
+
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+
+            if visited
+                .get(&next)
+                .is_some_and(|visit| visit.cost <= next_cost)
+            {
+                continue;
+            }
+
+            let estimate = next_cost + heuristic(&next);
+            let visit = Visit {
+                parent: Some(index),
+                cost: next_cost,
+            };
+            heap.push(Reverse((estimate, next_cost, visited.insert(next, visit))));
+        }
+    }
+
+    (visited, None)
+}
+
+/// Finds the path with the fewest steps from any of `starts` to a state that matches `is_target`.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_target: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let (visited, target) = run_bfs(starts, successors, is_target);
+    target.map(|index| visited.path_to(index))
+}
+
+/// Finds the fewest steps from any of `starts` to every state reachable from them.
+pub fn bfs_all<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let (visited, _) = run_bfs(starts, successors, |_| false);
+    visited.into_costs()
+}
+
+fn run_bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_target: impl FnMut(&S) -> bool,
+) -> (Visited<S, usize>, Option<usize>)
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = Visited::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        let visit = Visit {
+            parent: None,
+            cost: 0,
+        };
+        queue.push_back(visited.insert(start, visit));
+    }
+
+    while let Some(index) = queue.pop_front() {
+        let state = visited.states[index].clone();
+
+        if is_target(&state) {
+            return (visited, Some(index));
+        }
+
+        let cost = visited.visits[index].cost + 1;
+
+        for next in successors(&state) {
+            if visited.get(&next).is_none() {
+                let visit = Visit {
+                    parent: Some(index),
+                    cost,
+                };
+                queue.push_back(visited.insert(next, visit));
+            }
+        }
+    }
+
+    (visited, None)
+}
+
+/// Finds any path from any of `starts` to a state that matches `is_target`, going as deep as possible before backtracking.
+/// Every state is visited at most once, so the path is not necessarily the shortest.
+pub fn dfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_target: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = Visited::new();
+    let mut expanded = HashSet::new();
+    let mut stack: Vec<usize> = starts
+        .into_iter()
+        .map(|start| {
+            let visit = Visit {
+                parent: None,
+                cost: 0,
+            };
+            visited.insert(start, visit)
+        })
+        .collect();
+
+    // NOTE: reverse, so that the first start is explored first.
+    stack.reverse();
+
+    while let Some(index) = stack.pop() {
+        if !expanded.insert(index) {
+            continue;
+        }
+
+        let state = visited.states[index].clone();
+
+        if is_target(&state) {
+            return Some(visited.path_to(index));
+        }
+
+        let cost = visited.visits[index].cost + 1;
+        let mut next_states: Vec<S> = successors(&state).into_iter().collect();
+
+        // NOTE: push in reverse, so that the first successor is explored first.
+        next_states.reverse();
+
+        for next in next_states {
+            let is_expanded = visited
+                .indices
+                .get(&next)
+                .is_some_and(|index| expanded.contains(index));
+
+            if !is_expanded {
+                let visit = Visit {
+                    parent: Some(index),
+                    cost,
+                };
+                stack.push(visited.insert(next, visit));
+            }
+        }
+    }
+
+    None
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{astar, bfs, bfs_all, dfs, dijkstra, dijkstra_all};
+    use crate::grid::Grid;
+    use crate::point::Point2;
+
+    fn maze() -> Grid<char> {
+        ["S..#....", ".#.#.##.", ".#...#..", ".####.#.", "......#E"]
+            .join("\n")
+            .parse()
+            .unwrap()
+    }
+
+    fn open_neighbours(grid: &Grid<char>, point: Point2) -> Vec<Point2> {
+        point
+            .neighbours()
+            .filter(|next| grid.get(next.x, next.y).is_some_and(|c| *c != '#'))
+            .collect()
+    }
+
+    #[test]
+    fn finds_cheapest_paths() {
+        // going through 'b' is shorter, but more expensive.
+        let edges = |n: &char| match n {
+            'a' => vec![('b', 10), ('c', 1)],
+            'b' => vec![('e', 1)],
+            'c' => vec![('d', 1)],
+            'd' => vec![('e', 1)],
+            _ => vec![],
+        };
+
+        let path = dijkstra(['a'], edges, |n| *n == 'e').unwrap();
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.states, vec!['a', 'c', 'd', 'e']);
+        assert_eq!(*path.target(), 'e');
+
+        assert_eq!(dijkstra(['a'], edges, |n| *n == 'z'), None);
+
+        let costs = dijkstra_all(['a'], edges);
+        assert_eq!(costs.len(), 5);
+        assert_eq!(costs[&'b'], 10);
+        assert_eq!(costs[&'e'], 3);
+    }
+
+    #[test]
+    fn supports_several_starts_and_targets() {
+        let path = dijkstra(
+            [0, 7],
+            |n: &i32| [(n + 1, 1), (n - 1, 1)],
+            |n| *n == 3 || *n == 20,
+        )
+        .unwrap();
+        assert_eq!(path.states, vec![0, 1, 2, 3]);
+
+        let path = bfs([0, 5], |n: &i32| [n + 1, n - 1], |n| n.abs() == 4).unwrap();
+        assert_eq!(path.states, vec![5, 4]);
+
+        let path = dfs([9, 2], |n: &i32| [n + 1], |n| *n == 3 || *n == 10).unwrap();
+        assert_eq!(path.states, vec![9, 10]);
+
+        let steps = bfs_all([0, 10], |n: &i32| {
+            [n + 1, n - 1].into_iter().filter(|n| n.abs() <= 20)
+        });
+        assert_eq!(steps[&4], 4);
+        assert_eq!(steps[&6], 4);
+        assert_eq!(steps[&-2], 2);
+
+        let costs = dijkstra_all([0, 10], |n: &i32| {
+            [(n + 1, 1), (n - 1, 1)]
+                .into_iter()
+                .filter(|(n, _)| n.abs() <= 20)
+        });
+        assert_eq!(costs[&7], 3);
+    }
+
+    #[test]
+    fn finds_paths_on_grids() {
+        let grid = maze();
+        let start = Point2::new(0, 0);
+        let end = Point2::new(7, 4);
+        let successors = |p: &Point2| open_neighbours(&grid, *p);
+
+        let shortest = bfs([start], successors, |p| *p == end).unwrap();
+        assert_eq!(shortest.cost, 15);
+        assert_eq!(shortest.states.len(), 16);
+
+        let weighted = |p: &Point2| successors(p).into_iter().map(|next| (next, 1));
+        let path = astar(
+            [start],
+            weighted,
+            |p| p.manhattan_distance(end),
+            |p| *p == end,
+        );
+        assert_eq!(path.unwrap().cost, 15);
+
+        let any = dfs([start], successors, |p| *p == end).unwrap();
+        assert!(any.cost >= shortest.cost);
+        assert!(any
+            .states
+            .windows(2)
+            .all(|pair| pair[0].manhattan_distance(pair[1]) == 1));
+
+        let steps = bfs_all([start], successors);
+        assert_eq!(steps[&end], 15);
+        assert!(!steps.contains_key(&Point2::new(1, 1)));
+    }
+
+    #[test]
+    fn reports_unreachable_targets() {
+        let grid = maze();
+        let successors = |p: &Point2| open_neighbours(&grid, *p);
+        let wall = Point2::new(3, 0);
+
+        assert_eq!(bfs([Point2::ORIGIN], successors, |p| *p == wall), None);
+        assert_eq!(dfs([Point2::ORIGIN], successors, |p| *p == wall), None);
+    }
+}
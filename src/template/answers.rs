@@ -0,0 +1,245 @@
+/// Store of known correct answers, used to check that solutions still produce them after refactoring.
+/// Answers are recorded automatically when a submission is accepted, or can be added to the file by hand.
+use std::{collections::HashMap, fs, io, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, PuzzleId, Year};
+
+/// Path of the answers file of a year.
+pub fn get_path(year: Year) -> String {
+    format!("./data/{year}/answers.json")
+}
+
+/// Known correct answers for a single day.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Answer {
+    pub day: Day,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl Answer {
+    pub fn part(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Known correct answers for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Answers {
+    pub data: Vec<Answer>,
+}
+
+impl Answers {
+    /// Dehydrate answers of a year to a JSON file.
+    pub fn store_file(&self, year: Year) -> Result<(), io::Error> {
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(get_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers of a year from a JSON file. If not present, returns no answers.
+    /// Unlike other data files, a broken answers file is an error: recorded answers can not be regenerated.
+    pub fn try_read_from_file(year: Year) -> Result<Self, String> {
+        match fs::read_to_string(get_path(year)) {
+            Ok(s) => Answers::try_from(s).map_err(|e| format!("{}: {e}", get_path(year))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(format!("{}: {e}", get_path(year))),
+        }
+    }
+
+    /// Returns the known answer for a part, if there is one.
+    pub fn get(&self, day: Day, part: u8) -> Option<&str> {
+        self.data
+            .iter()
+            .find(|a| a.day == day)
+            .and_then(|a| a.part(part))
+    }
+
+    /// Set the answer for a part, overwriting a previously known answer.
+    pub fn set(&mut self, day: Day, part: u8, answer: &str) {
+        let index = match self.data.iter().position(|a| a.day == day) {
+            Some(index) => index,
+            None => {
+                self.data.push(Answer {
+                    day,
+                    part_1: None,
+                    part_2: None,
+                });
+                self.data.sort_unstable_by_key(|a| a.day);
+                self.data.iter().position(|a| a.day == day).unwrap()
+            }
+        };
+
+        let entry = &mut self.data[index];
+        match part {
+            1 => entry.part_1 = Some(answer.into()),
+            2 => entry.part_2 = Some(answer.into()),
+            _ => {}
+        }
+    }
+}
+
+/// Record an accepted answer in the answers file.
+/// Leaves the file untouched if it can not be read, so that a broken file does not lose its other answers.
+pub fn record(puzzle: PuzzleId, part: u8, answer: &str) -> Result<(), String> {
+    let mut answers = Answers::try_read_from_file(puzzle.year)?;
+    answers.set(puzzle.day, part, answer);
+    answers.store_file(puzzle.year).map_err(|e| e.to_string())
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answers> for JsonValue {
+    fn from(value: &Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected answers file to be a JSON object.")?;
+
+        let data = json
+            .get("data")
+            .and_then(|v| v.get::<Vec<JsonValue>>())
+            .ok_or("expected answers file to contain a `data` array.")?;
+
+        Ok(Answers {
+            data: data
+                .iter()
+                .map(Answer::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<&Answer> for JsonValue {
+    fn from(value: &Answer) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+
+        for (key, part) in [("part_1", &value.part_1), ("part_2", &value.part_2)] {
+            map.insert(
+                key.into(),
+                match part {
+                    Some(x) => JsonValue::String(x.clone()),
+                    None => JsonValue::Null,
+                },
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Answer {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answer to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected answer.day to be a Day struct.")?;
+
+        let parse_part = |key: &str| match json.get(key) {
+            None => Ok(None),
+            Some(v) if v.is_null() => Ok(None),
+            Some(v) => v
+                .get::<String>()
+                .cloned()
+                .map(Some)
+                .ok_or(format!("Expected answer.{key} to be null or a string.")),
+        };
+
+        Ok(Answer {
+            day,
+            part_1: parse_part("part_1")?,
+            part_2: parse_part("part_2")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answer, Answers};
+    use crate::day;
+    use tinyjson::JsonValue;
+
+    #[test]
+    fn parses_answers() {
+        let json =
+            r#"{"data":[{"day":"01","part_1":"142","part_2":null},{"day":"02","part_1":"8"}]}"#;
+        let answers = Answers::try_from(json.to_string()).unwrap();
+        assert_eq!(answers.get(day!(1), 1), Some("142"));
+        assert_eq!(answers.get(day!(1), 2), None);
+        assert_eq!(answers.get(day!(2), 1), Some("8"));
+        assert_eq!(answers.get(day!(3), 1), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_numeric_answers() {
+        Answers::try_from(r#"{"data":[{"day":"01","part_1":142}]}"#.to_string()).unwrap();
+    }
+
+    #[test]
+    fn sets_answers_in_order() {
+        let mut answers = Answers::default();
+        answers.set(day!(5), 2, "35");
+        answers.set(day!(2), 1, "8");
+        answers.set(day!(5), 2, "46");
+
+        assert_eq!(
+            answers.data,
+            vec![
+                Answer {
+                    day: day!(2),
+                    part_1: Some("8".into()),
+                    part_2: None,
+                },
+                Answer {
+                    day: day!(5),
+                    part_1: None,
+                    part_2: Some("46".into()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn roundtrips_answers() {
+        let mut answers = Answers::default();
+        answers.set(day!(12), 1, "a\nb");
+        answers.set(day!(12), 2, "21");
+
+        let json = JsonValue::from(&answers).stringify().unwrap();
+        assert_eq!(Answers::try_from(json).unwrap(), answers);
+    }
+}
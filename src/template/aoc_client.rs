@@ -0,0 +1,303 @@
+/// Client for adventofcode.com, used to download inputs and puzzle descriptions and to submit answers.
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::template::{markdown, PuzzleId};
+
+/// Base URL of the advent of code website. Can be overridden via `AOC_BASE_URL`, e.g. to test against a mock server.
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Name of the session cookie file, looked up in the home directory. Same location as used by `aoc-cli`.
+const SESSION_FILE_NAME: &str = ".adventofcode.session";
+
+const USER_AGENT: &str = "github.com/fspoettel/advent-of-code-rust";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    Request(String),
+    IO(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Set the AOC_SESSION environment variable or write it to ~/{SESSION_FILE_NAME}."
+            ),
+            AocClientError::Request(e) => write!(f, "request failed: {e}"),
+            AocClientError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(code, response) => AocClientError::Request(format!(
+                "{} responded with status {code}. Is your session cookie still valid?",
+                response.get_url()
+            )),
+            ureq::Error::Transport(e) => AocClientError::Request(e.to_string()),
+        }
+    }
+}
+
+/// Authenticated client for the advent of code website.
+pub struct AocClient {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl AocClient {
+    /// Create a client from the environment.
+    /// The session cookie is read from `AOC_SESSION`, falling back to `~/.adventofcode.session`.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let session = get_session().ok_or(AocClientError::SessionNotFound)?;
+
+        let base_url = env::var("AOC_BASE_URL").map_or_else(
+            |_| DEFAULT_BASE_URL.into(),
+            |x| x.trim_end_matches('/').into(),
+        );
+
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Ok(Self {
+            agent,
+            base_url,
+            session,
+        })
+    }
+
+    /// Fetch the puzzle input for a day.
+    pub fn input(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", self.day_url(puzzle)))
+    }
+
+    /// Fetch the puzzle description for a day, converted to markdown.
+    pub fn puzzle(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        let html = self.get(&self.day_url(puzzle))?;
+        Ok(markdown::from_html(&html))
+    }
+
+    /// Post an answer for a part. Returns the response message, converted to markdown.
+    pub fn submit(
+        &self,
+        puzzle: PuzzleId,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, AocClientError> {
+        let url = format!("{}/answer", self.day_url(puzzle));
+        let level = part.to_string();
+
+        let html = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &level), ("answer", answer)])?
+            .into_string()?;
+
+        Ok(markdown::from_html(&html))
+    }
+
+    fn day_url(&self, puzzle: PuzzleId) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url,
+            puzzle.year,
+            puzzle.day.into_inner()
+        )
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        let response = self.agent.get(url).set("Cookie", &self.cookie()).call()?;
+        Ok(response.into_string()?)
+    }
+}
+
+/// Download the input and puzzle description for a day to the data folder.
+pub fn download(puzzle: PuzzleId) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+
+    let input_path = puzzle.data_path("inputs", "txt");
+    let puzzle_path = puzzle.data_path("puzzles", "md");
+
+    create_parent_dir(&input_path)?;
+    create_parent_dir(&puzzle_path)?;
+
+    fs::write(&input_path, client.input(puzzle)?)?;
+    fs::write(&puzzle_path, client.puzzle(puzzle)?)?;
+
+    println!("---");
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    Ok(())
+}
+
+/// Fetch the puzzle description for a day, store it in the data folder and print it.
+pub fn read(puzzle: PuzzleId) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+    let description = client.puzzle(puzzle)?;
+
+    let puzzle_path = puzzle.data_path("puzzles", "md");
+    create_parent_dir(&puzzle_path)?;
+
+    fs::write(puzzle_path, &description)?;
+    println!("{description}");
+    Ok(())
+}
+
+/// Submit an answer for a part. Returns the response message.
+pub fn submit(puzzle: PuzzleId, part: u8, answer: &str) -> Result<String, AocClientError> {
+    AocClient::from_env()?.submit(puzzle, part, answer)
+}
+
+fn create_parent_dir(path: &str) -> Result<(), io::Error> {
+    match Path::new(path).parent() {
+        Some(dir) => fs::create_dir_all(dir),
+        None => Ok(()),
+    }
+}
+
+fn get_session() -> Option<String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        return Some(session.trim().to_string()).filter(|x| !x.is_empty());
+    }
+
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    read_session_file(&PathBuf::from(home).join(SESSION_FILE_NAME))
+}
+
+fn read_session_file(path: &Path) -> Option<String> {
+    let session = fs::read_to_string(path).ok()?;
+    Some(session.trim().to_string()).filter(|x| !x.is_empty())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::AocClient;
+    use crate::template::PuzzleId;
+    use crate::{day, year};
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    /// Serve a single response on a local port. Returns the base URL and a handle yielding the raw request.
+    fn mock_server(body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(x) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    content_length = x.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut payload = vec![0; content_length];
+            reader.read_exact(&mut payload).unwrap();
+            request.push_str(&String::from_utf8(payload).unwrap());
+
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    fn client(base_url: String) -> AocClient {
+        AocClient {
+            agent: ureq::agent(),
+            base_url,
+            session: "abc".into(),
+        }
+    }
+
+    #[test]
+    fn fetches_input() {
+        let (base_url, server) = mock_server("1\n2\n");
+        assert_eq!(
+            client(base_url)
+                .input(PuzzleId::new(year!(2023), day!(1)))
+                .unwrap(),
+            "1\n2\n"
+        );
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2023/day/1/input HTTP/1.1"));
+        assert!(request.contains("Cookie: session=abc"));
+    }
+
+    #[test]
+    fn fetches_puzzle_as_markdown() {
+        let (base_url, server) =
+            mock_server("<main><article><h2>--- Day 5 ---</h2><p>Seeds.</p></article></main>");
+        assert_eq!(
+            client(base_url)
+                .puzzle(PuzzleId::new(year!(2023), day!(5)))
+                .unwrap(),
+            "## --- Day 5 ---\n\nSeeds.\n"
+        );
+        assert!(server
+            .join()
+            .unwrap()
+            .starts_with("GET /2023/day/5 HTTP/1.1"));
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (base_url, server) =
+            mock_server("<main><article><p>That's the right answer!</p></article></main>");
+        assert_eq!(
+            client(base_url)
+                .submit(PuzzleId::new(year!(2023), day!(12)), 2, "42")
+                .unwrap(),
+            "That's the right answer!\n"
+        );
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2023/day/12/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+}
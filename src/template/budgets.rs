@@ -0,0 +1,171 @@
+/// Time limits of the days of a year, configured by hand in `data/<year>/budgets.json`.
+/// A timeout stops a day that runs too long, a budget flags days whose benchmarks are too slow.
+/// Both have a default for the whole year, which can be overridden per day:
+///
+/// ```json
+/// {
+///   "timeout": 30,
+///   "budget": 1,
+///   "days": {
+///     "23": { "timeout": 300, "budget": 10 }
+///   }
+/// }
+/// ```
+use std::{collections::HashMap, fs, io, str::FromStr, time::Duration};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Year};
+
+/// Path of the budgets file of a year.
+pub fn get_path(year: Year) -> String {
+    format!("./data/{year}/budgets.json")
+}
+
+/// Time limits of a single day, or the defaults of a year.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Limits {
+    /// Running the day is stopped after this long.
+    pub timeout: Option<Duration>,
+    /// Benchmarks of the day are flagged if they take longer than this.
+    pub budget: Option<Duration>,
+}
+
+/// Time limits of the days of a year.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Budgets {
+    pub default: Limits,
+    pub days: HashMap<Day, Limits>,
+}
+
+impl Budgets {
+    /// Read the budgets of a year from its JSON file. If not present, returns no limits.
+    pub fn read_from_file(year: Year) -> Self {
+        let s = match fs::read_to_string(get_path(year)) {
+            Ok(s) => Budgets::try_from(s),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Budgets::default(),
+            Err(e) => Err(e.to_string()),
+        };
+
+        match s {
+            Ok(budgets) => budgets,
+            Err(e) => {
+                eprintln!("{e}");
+                Budgets::default()
+            }
+        }
+    }
+
+    /// Timeout of a day, falling back to the default timeout.
+    pub fn timeout(&self, day: Day) -> Option<Duration> {
+        self.days
+            .get(&day)
+            .and_then(|limits| limits.timeout)
+            .or(self.default.timeout)
+    }
+
+    /// Budget of a day, falling back to the default budget.
+    pub fn budget(&self, day: Day) -> Option<Duration> {
+        self.days
+            .get(&day)
+            .and_then(|limits| limits.budget)
+            .or(self.default.budget)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl TryFrom<String> for Budgets {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected budgets file to be a JSON object.")?;
+
+        let mut days = HashMap::new();
+
+        if let Some(value) = json.get("days") {
+            let map = value
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or("expected budgets.days to be a JSON object.")?;
+
+            for (key, limits) in map {
+                let day = Day::from_str(key)
+                    .map_err(|_| format!("expected budgets.days key {key:?} to be a day."))?;
+
+                let limits = limits
+                    .get::<HashMap<String, JsonValue>>()
+                    .ok_or(format!("expected budgets.days.{key} to be a JSON object."))?;
+
+                days.insert(day, Limits::try_from(limits)?);
+            }
+        }
+
+        Ok(Budgets {
+            default: Limits::try_from(json)?,
+            days,
+        })
+    }
+}
+
+impl TryFrom<&HashMap<String, JsonValue>> for Limits {
+    type Error = String;
+
+    fn try_from(json: &HashMap<String, JsonValue>) -> Result<Self, Self::Error> {
+        // NOTE: limits are given in seconds, missing or null limits are not enforced.
+        let parse_seconds = |key: &str| match json.get(key) {
+            Some(v) if !v.is_null() => v
+                .get::<f64>()
+                .and_then(|x| Duration::try_from_secs_f64(*x).ok())
+                .map(Some)
+                .ok_or(format!(
+                    "expected {key} to be a non-negative number of seconds."
+                )),
+            _ => Ok(None),
+        };
+
+        Ok(Limits {
+            timeout: parse_seconds("timeout")?,
+            budget: parse_seconds("budget")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Budgets;
+    use crate::day;
+    use std::time::Duration;
+
+    #[test]
+    fn parses_budgets() {
+        let json =
+            r#"{"timeout":30,"budget":0.5,"days":{"23":{"timeout":300},"5":{"budget":null}}}"#;
+        let budgets = Budgets::try_from(json.to_string()).unwrap();
+
+        assert_eq!(budgets.timeout(day!(1)), Some(Duration::from_secs(30)));
+        assert_eq!(budgets.timeout(day!(23)), Some(Duration::from_secs(300)));
+        assert_eq!(budgets.budget(day!(23)), Some(Duration::from_millis(500)));
+        assert_eq!(budgets.budget(day!(5)), Some(Duration::from_millis(500)));
+    }
+
+    #[test]
+    fn parses_empty_budgets() {
+        let budgets = Budgets::try_from("{}".to_string()).unwrap();
+        assert_eq!(budgets, Budgets::default());
+        assert_eq!(budgets.timeout(day!(1)), None);
+        assert_eq!(budgets.budget(day!(1)), None);
+    }
+
+    #[test]
+    fn rejects_invalid_budgets() {
+        assert!(Budgets::try_from(r#"{"timeout":-1}"#.to_string()).is_err());
+        assert!(Budgets::try_from(r#"{"budget":"1s"}"#.to_string()).is_err());
+        assert!(Budgets::try_from(r#"{"days":{"26":{"timeout":1}}}"#.to_string()).is_err());
+        assert!(Budgets::try_from(r#"{"days":{"01":5}}"#.to_string()).is_err());
+    }
+}
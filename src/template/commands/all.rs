@@ -1,5 +1,42 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::process;
+use std::time::Duration;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::{
+    all_days,
+    budgets::Budgets,
+    registry::Registry,
+    run_multi::{print_summary, run_multi},
+    Year,
+};
+
+pub fn handle(
+    registry: &Registry,
+    year: Year,
+    is_release: bool,
+    jobs: usize,
+    timeout: Option<Duration>,
+) {
+    let mut budgets = Budgets::read_from_file(year);
+
+    // NOTE: `--timeout` replaces the default timeout, days with a timeout of their own keep it.
+    if timeout.is_some() {
+        budgets.default.timeout = timeout;
+    }
+
+    let run = run_multi(
+        registry,
+        year,
+        &all_days().collect(),
+        is_release,
+        false,
+        false,
+        jobs,
+        &budgets,
+    );
+
+    print_summary(&run);
+
+    if run.has_failures() {
+        process::exit(1);
+    }
 }
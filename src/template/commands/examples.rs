@@ -0,0 +1,155 @@
+use std::{
+    fs,
+    io::{self, BufRead, Write},
+    process,
+};
+
+use crate::template::example_manifest::{self, Manifest};
+use crate::template::examples::{self, PartExamples};
+use crate::template::PuzzleId;
+
+/// Number of lines shown per code block when asking which one is the example.
+const PREVIEW_LINES: usize = 4;
+
+pub fn handle(puzzle: PuzzleId, overwrite: bool) {
+    let puzzle_path = puzzle.data_path("puzzles", "md");
+
+    let Ok(markdown) = fs::read_to_string(&puzzle_path) else {
+        eprintln!(
+            "Could not read \"{puzzle_path}\". Run `cargo download {} --year {}` first.",
+            puzzle.day, puzzle.year
+        );
+        process::exit(1);
+    };
+
+    let mut manifest = match Manifest::read_from_file(puzzle) {
+        Ok(manifest) => manifest,
+        Err(e) => {
+            eprintln!(
+                "Failed to read \"{}\": {e}",
+                example_manifest::get_path(puzzle)
+            );
+            process::exit(1);
+        }
+    };
+
+    let (part_one, part_two) = examples::parse(&markdown);
+
+    let Some(example) = choose_block(&part_one, 1, false) else {
+        eprintln!("Could not find any code blocks in \"{puzzle_path}\".");
+        process::exit(1);
+    };
+
+    let file = format!("{}.txt", puzzle.day);
+    write_example(puzzle, &file, example, overwrite);
+    fill_manifest(&mut manifest, 1, &file, &part_one);
+
+    if let Some(part_two) = part_two {
+        // part two often reuses the example of part one, only ask if it has blocks of its own.
+        let file = match choose_block(&part_two, 2, true) {
+            Some(block) if block != example => {
+                let file = format!("{}-2.txt", puzzle.day);
+                write_example(puzzle, &file, block, overwrite);
+                file
+            }
+            _ => file,
+        };
+
+        fill_manifest(&mut manifest, 2, &file, &part_two);
+    }
+
+    match manifest.store_file(puzzle) {
+        Ok(()) => println!(
+            "Updated example manifest \"{}\"",
+            example_manifest::get_path(puzzle)
+        ),
+        Err(e) => {
+            eprintln!("Failed to update example manifest: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Pick the code block holding the example of a part, asking when there are several candidates.
+/// With `optional`, the user can also decline to pick one.
+fn choose_block(part_examples: &PartExamples, part: u8, optional: bool) -> Option<&str> {
+    match part_examples.blocks.as_slice() {
+        [] => None,
+        [block] if !optional => Some(block),
+        blocks => {
+            println!("Found {} code blocks for part {part}:", blocks.len());
+
+            for (i, block) in blocks.iter().enumerate() {
+                println!("--- [{}] ---", i + 1);
+                for line in block.lines().take(PREVIEW_LINES) {
+                    println!("{line}");
+                }
+                if block.lines().count() > PREVIEW_LINES {
+                    println!("...");
+                }
+            }
+
+            if optional {
+                println!("--- [0] ---");
+                println!("(reuse the example of part one)");
+            }
+
+            let choice = prompt(&format!(
+                "Which block is the example for part {part}? [{}] ",
+                usize::from(!optional)
+            ));
+
+            match choice.map_or(Ok(usize::from(!optional)), |x| x.parse::<usize>()) {
+                Ok(0) if optional => None,
+                Ok(n) if (1..=blocks.len()).contains(&n) => Some(&blocks[n - 1]),
+                _ => {
+                    eprintln!("Invalid choice.");
+                    process::exit(1);
+                }
+            }
+        }
+    }
+}
+
+/// Read a line from stdin. Returns [`None`] if it is empty, i.e. the default should be used.
+fn prompt(message: &str) -> Option<String> {
+    print!("{message}");
+    io::stdout().flush().ok();
+
+    let mut line = String::new();
+    io::stdin().lock().read_line(&mut line).ok()?;
+
+    Some(line.trim().to_string()).filter(|x| !x.is_empty())
+}
+
+fn write_example(puzzle: PuzzleId, file: &str, example: &str, overwrite: bool) {
+    let path = format!("data/{}/examples/{file}", puzzle.year);
+    let is_empty = fs::read_to_string(&path).map_or(true, |x| x.trim().is_empty());
+
+    if !is_empty && !overwrite {
+        println!("Skipped \"{path}\" as it is not empty. Pass `--overwrite` to replace it.");
+        return;
+    }
+
+    match fs::write(&path, example) {
+        Ok(()) => println!("Wrote example to \"{path}\""),
+        Err(e) => {
+            eprintln!("Failed to write example: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+fn fill_manifest(manifest: &mut Manifest, part: u8, file: &str, part_examples: &PartExamples) {
+    if part_examples.answer.is_none() {
+        println!("Could not find an expected answer for part {part}.");
+    }
+
+    if manifest.fill(part, file, part_examples.answer.clone()) {
+        if let Some(answer) = &part_examples.answer {
+            println!("Expecting \"{answer}\" for part {part}");
+        }
+    } else {
+        println!("Kept the expected answer of part {part}, as it was already set.");
+    }
+}
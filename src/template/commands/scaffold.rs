@@ -1,13 +1,23 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::template::Day;
+use crate::template::example_manifest::{self, Manifest};
+use crate::template::{examples, PuzzleId};
 
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+/// Directory holding the named module templates, e.g. `templates/grid.txt`.
+const TEMPLATES_DIR: &str = "templates";
+
+pub const DEFAULT_TEMPLATE_NAME: &str = "default";
+
+/// Embedded copy of the default template, used if the templates directory was removed.
+const DEFAULT_TEMPLATE: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/templates/default.txt"
+));
 
 fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
@@ -17,10 +27,99 @@ fn create_file(path: &str) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create(true).open(path)
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Read a named template from the templates directory.
+fn load_template(name: &str) -> Result<String, String> {
+    let path = Path::new(TEMPLATES_DIR).join(format!("{name}.txt"));
+
+    match fs::read_to_string(&path) {
+        Ok(template) => Ok(template),
+        Err(_) if name == DEFAULT_TEMPLATE_NAME => Ok(DEFAULT_TEMPLATE.into()),
+        Err(e) => {
+            let mut available: Vec<String> = fs::read_dir(TEMPLATES_DIR)
+                .into_iter()
+                .flatten()
+                .filter_map(|entry| {
+                    let path = entry.ok()?.path();
+                    (path.extension()? == "txt").then_some(())?;
+                    Some(path.file_stem()?.to_str()?.to_string())
+                })
+                .collect();
+            available.sort_unstable();
+
+            Err(format!(
+                "could not read template \"{}\": {e}. Available templates: {}.",
+                path.display(),
+                available.join(", ")
+            ))
+        }
+    }
+}
+
+/// Answers of the examples of both parts, read from the puzzle description if it was downloaded already.
+fn example_answers(markdown: &str) -> [Option<String>; 2] {
+    let (part_one, part_two) = examples::parse(markdown);
+    [part_one.answer, part_two.and_then(|x| x.answer)]
+}
+
+/// Values of the placeholders a template can use.
+/// Title and example answers are read from the puzzle description, if it was downloaded already.
+fn placeholders(
+    puzzle: PuzzleId,
+    markdown: &str,
+    [part_one, part_two]: &[Option<String>; 2],
+) -> Vec<(&'static str, String)> {
+    let unknown = || "?".to_string();
+
+    vec![
+        ("%DAY_NUMBER%", puzzle.day.into_inner().to_string()),
+        ("%DAY%", puzzle.day.to_string()),
+        ("%YEAR%", puzzle.year.to_string()),
+        (
+            "%TITLE%",
+            examples::title(markdown)
+                .map_or_else(|| format!("Day {}", puzzle.day.into_inner()), Into::into),
+        ),
+        (
+            "%PART_ONE_ANSWER%",
+            part_one.clone().unwrap_or_else(unknown),
+        ),
+        (
+            "%PART_TWO_ANSWER%",
+            part_two.clone().unwrap_or_else(unknown),
+        ),
+    ]
+}
+
+fn render(template: &str, placeholders: &[(&str, String)]) -> String {
+    placeholders
+        .iter()
+        .fold(template.to_string(), |acc, (key, value)| {
+            acc.replace(key, value)
+        })
+}
+
+pub fn handle(puzzle: PuzzleId, template: &str) {
+    let input_path = puzzle.data_path("inputs", "txt");
+    let example_path = puzzle.data_path("examples", "txt");
+    let module_path = puzzle.bin_path();
+
+    for folder in ["inputs", "examples", "puzzles"] {
+        if let Err(e) = fs::create_dir_all(format!("data/{}/{folder}", puzzle.year)) {
+            eprintln!("Failed to create data folder: {e}");
+            process::exit(1);
+        }
+    }
+
+    let markdown = fs::read_to_string(puzzle.data_path("puzzles", "md")).unwrap_or_default();
+    let answers = example_answers(&markdown);
+
+    let template = match load_template(template) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!("Failed to load template: {e}");
+            process::exit(1);
+        }
+    };
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -30,11 +129,7 @@ pub fn handle(day: Day) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    match file.write_all(render(&template, &placeholders(puzzle, &markdown, &answers)).as_bytes()) {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -44,26 +139,42 @@ pub fn handle(day: Day) {
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    // files that were downloaded before scaffolding are kept as they are.
+    for (name, path) in [("input", &input_path), ("example", &example_path)] {
+        if Path::new(path).exists() {
+            continue;
         }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+
+        match create_file(path) {
+            Ok(_) => {
+                println!("Created empty {name} file \"{path}\"");
+            }
+            Err(e) => {
+                eprintln!("Failed to create {name} file: {e}");
+                process::exit(1);
+            }
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+    let manifest_path = example_manifest::get_path(puzzle);
+
+    if Path::new(&manifest_path).exists() {
+        println!("Kept existing example manifest \"{}\"", &manifest_path);
+    } else {
+        match Manifest::scaffold(puzzle, answers).store_file(puzzle) {
+            Ok(()) => {
+                println!("Created example manifest \"{}\"", &manifest_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to create example manifest: {e}");
+                process::exit(1);
+            }
         }
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    println!(
+        "🎄 Type `cargo solve {} --year {}` to run your solution.",
+        puzzle.day, puzzle.year
+    );
 }
@@ -1,11 +1,44 @@
 use std::collections::HashSet;
+use std::process;
+use std::time::Duration;
 
-use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::budgets::Budgets;
+use crate::template::registry::Registry;
+use crate::template::run_multi::{run_multi, DayStatus};
+use crate::template::runner::phase_name;
+use crate::template::timings::{TimingChange, Timings};
+use crate::template::timings_history;
+use crate::template::{all_days, readme_benchmarks, Day, PuzzleId, Year, ANSI_BOLD, ANSI_RESET};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+/// Default slowdown in percent above which a phase is reported as a regression.
+pub const DEFAULT_REGRESSION_THRESHOLD: f64 = 10.0;
+
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    registry: &Registry,
+    year: Year,
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    stats: bool,
+    isolated: bool,
+    threshold: f64,
+    fail_on_regression: bool,
+    timeout: Option<Duration>,
+    budget: Option<Duration>,
+    fail_over_budget: bool,
+) {
+    let stored_timings = Timings::read_from_file(year);
+    let mut budgets = Budgets::read_from_file(year);
+
+    // NOTE: `--timeout` and `--budget` replace the defaults, days with limits of their own keep them.
+    if timeout.is_some() {
+        budgets.default.timeout = timeout;
+    }
+
+    if budget.is_some() {
+        budgets.default.budget = budget;
+    }
 
     let days_to_run = day.map_or_else(
         || {
@@ -21,14 +54,42 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let run = run_multi(
+        registry,
+        year,
+        &days_to_run,
+        true,
+        true,
+        isolated,
+        1,
+        &budgets,
+    );
+    let timings = run.timings.unwrap();
+
+    let changes = stored_timings.compare(&timings);
+    let regressions = print_changes(&changes, threshold);
+    let over_budget = print_over_budget(&timings, &run.statuses, &budgets);
+
+    if fail_on_regression && regressions > 0 {
+        eprintln!("\n{regressions} phase(s) regressed by more than {threshold}%.");
+        process::exit(1);
+    }
+
+    if fail_over_budget && over_budget > 0 {
+        eprintln!("\n{over_budget} day(s) took longer than their budget.");
+        process::exit(1);
+    }
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        merged_timings.store_file(year).unwrap();
+
+        if let Err(e) = timings_history::append(year, &timings) {
+            eprintln!("Failed to append to timings history: {e}");
+        }
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(year, merged_timings, stats) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
@@ -38,3 +99,87 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         }
     }
 }
+
+/// Print how the runtime of a day evolved, based on the timings history.
+pub fn handle_history(puzzle: PuzzleId) {
+    println!("{ANSI_BOLD}Day {}{ANSI_RESET}", puzzle.day);
+    println!("------");
+    timings_history::print_history(puzzle, &timings_history::read_for_puzzle(puzzle));
+}
+
+/// Print how each phase changed compared to the stored timings. Returns the number of regressions.
+fn print_changes(changes: &[TimingChange], threshold: f64) -> usize {
+    if changes.is_empty() {
+        return 0;
+    }
+
+    println!("\n{ANSI_BOLD}Compared to stored timings:{ANSI_RESET}");
+
+    let mut regressions = 0;
+
+    for change in changes {
+        let marker = if change.is_regression(threshold) {
+            regressions += 1;
+            format!(" {ANSI_BOLD}▲ regression{ANSI_RESET}")
+        } else {
+            String::new()
+        };
+
+        println!(
+            "Day {} {}: {} -> {} ({:+.1}%){marker}",
+            change.day,
+            phase_name(change.part),
+            format_nanos(change.old_nanos),
+            format_nanos(change.new_nanos),
+            change.percent(),
+        );
+    }
+
+    regressions
+}
+
+/// Print the days that took longer than their budget, including days that timed out.
+/// Returns the number of such days.
+fn print_over_budget(timings: &Timings, statuses: &[(Day, DayStatus)], budgets: &Budgets) -> usize {
+    let mut lines: Vec<(Day, String)> = statuses
+        .iter()
+        .filter(|(_, status)| *status == DayStatus::TimedOut)
+        .map(|(day, _)| (*day, "timed out".to_string()))
+        .collect();
+
+    for timing in &timings.data {
+        let Some(budget) = budgets.budget(timing.day) else {
+            continue;
+        };
+
+        #[allow(clippy::cast_precision_loss)]
+        let budget_nanos = budget.as_nanos() as f64;
+
+        if timing.total_nanos > budget_nanos {
+            lines.push((
+                timing.day,
+                format!("{} (budget {budget:.1?})", format_nanos(timing.total_nanos)),
+            ));
+        }
+    }
+
+    if lines.is_empty() {
+        return 0;
+    }
+
+    lines.sort_by_key(|(day, _)| *day);
+
+    println!("\n{ANSI_BOLD}Over budget:{ANSI_RESET}");
+
+    for (day, line) in &lines {
+        println!("Day {day}: {line}");
+    }
+
+    lines.len()
+}
+
+fn format_nanos(nanos: f64) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let duration = Duration::from_nanos(nanos.round() as u64);
+    format!("{duration:.1?}")
+}
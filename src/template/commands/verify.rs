@@ -0,0 +1,120 @@
+use std::path::Path;
+use std::process;
+
+use crate::template::answers::Answers;
+use crate::template::registry::Registry;
+use crate::template::run_multi::{print_day_header, run_day_silent};
+use crate::template::{all_days, Day, PuzzleId, Year, ANSI_BOLD, ANSI_RESET};
+
+/// Outcome of checking a part against its known answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Verdict {
+    Pass,
+    Fail,
+    Missing,
+}
+
+pub fn handle(registry: &Registry, year: Year, day: Option<Day>, is_release: bool) {
+    let answers = match Answers::try_read_from_file(year) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Failed to read answers: {e}");
+            process::exit(1);
+        }
+    };
+
+    let puzzles: Vec<PuzzleId> = day.map_or_else(
+        // when no day is given, skip days that have not been scaffolded yet.
+        || {
+            all_days()
+                .map(|day| PuzzleId::new(year, day))
+                .filter(|puzzle| {
+                    registry.get(*puzzle).is_some() || Path::new(&puzzle.bin_path()).exists()
+                })
+                .collect()
+        },
+        |day| vec![PuzzleId::new(year, day)],
+    );
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for (i, puzzle) in puzzles.into_iter().enumerate() {
+        print_day_header(puzzle.day, i > 0);
+
+        let output = run_day_silent(registry, puzzle, is_release, None);
+
+        if let Some(error) = &output.error {
+            eprintln!("{error}");
+        }
+
+        for part in [1, 2] {
+            let actual = output
+                .results
+                .iter()
+                .find(|r| r.part == part)
+                .and_then(|r| r.answer.as_deref());
+            let expected = answers.get(puzzle.day, part);
+
+            let Some(verdict) = verdict(expected, actual) else {
+                continue;
+            };
+
+            match verdict {
+                Verdict::Pass => {
+                    passed += 1;
+                    println!("Part {part}: ✅ {}", actual.unwrap_or_default());
+                }
+                Verdict::Fail => {
+                    failed += 1;
+                    println!(
+                        "Part {part}: ❌ got {}, expected {}",
+                        actual.unwrap_or("nothing"),
+                        expected.unwrap_or_default()
+                    );
+                }
+                Verdict::Missing => {
+                    missing += 1;
+                    println!(
+                        "Part {part}: ❔ {} (no known answer)",
+                        actual.unwrap_or_default()
+                    );
+                }
+            }
+        }
+    }
+
+    println!(
+        "\n{ANSI_BOLD}Verified:{ANSI_RESET} {passed} passed, {failed} failed, {missing} missing."
+    );
+
+    if failed > 0 {
+        process::exit(1);
+    }
+}
+
+/// Compare the answer a part produced to its known answer.
+/// Returns `None` for parts that are neither solved nor have a known answer.
+fn verdict(expected: Option<&str>, actual: Option<&str>) -> Option<Verdict> {
+    match (expected, actual) {
+        (Some(expected), Some(actual)) if expected == actual => Some(Verdict::Pass),
+        (Some(_), _) => Some(Verdict::Fail),
+        (None, Some(_)) => Some(Verdict::Missing),
+        (None, None) => None,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{verdict, Verdict};
+
+    #[test]
+    fn classifies_answers() {
+        assert_eq!(verdict(Some("42"), Some("42")), Some(Verdict::Pass));
+        assert_eq!(verdict(Some("42"), Some("41")), Some(Verdict::Fail));
+        assert_eq!(verdict(Some("42"), None), Some(Verdict::Fail));
+        assert_eq!(verdict(None, Some("42")), Some(Verdict::Missing));
+        assert_eq!(verdict(None, None), None);
+    }
+}
@@ -0,0 +1,303 @@
+/// Per-day manifest of example inputs and the answers expected for them, stored next to the example files.
+/// The build script generates one test per listed example, which calls [`check`].
+use std::{collections::HashMap, fmt::Display, fs, io, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::registry::Solution;
+use crate::template::runner::RunMode;
+use crate::template::PuzzleId;
+
+/// Path of the manifest of a day, e.g. `data/2023/examples/01.json`.
+pub fn get_path(puzzle: PuzzleId) -> String {
+    puzzle.data_path("examples", "json")
+}
+
+/// An example input of a part. `None` is expected if the part is not solved yet.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Example {
+    pub file: String,
+    pub part: u8,
+    pub expected: Option<String>,
+}
+
+/// The examples of a single day.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Manifest {
+    pub examples: Vec<Example>,
+}
+
+impl Manifest {
+    /// The manifest of a freshly scaffolded day: the default example file for both parts,
+    /// expecting the example answers of the puzzle description if they are known.
+    pub fn scaffold(puzzle: PuzzleId, expected: [Option<String>; 2]) -> Self {
+        Self {
+            examples: (1..=2)
+                .zip(expected)
+                .map(|(part, expected)| Example {
+                    file: format!("{}.txt", puzzle.day),
+                    part,
+                    expected,
+                })
+                .collect(),
+        }
+    }
+
+    /// Dehydrate the manifest of a day to a JSON file.
+    pub fn store_file(&self, puzzle: PuzzleId) -> Result<(), io::Error> {
+        fs::write(get_path(puzzle), self.to_string())
+    }
+
+    /// Rehydrate the manifest of a day from a JSON file. If not present, returns no examples.
+    pub fn read_from_file(puzzle: PuzzleId) -> Result<Self, String> {
+        match fs::read_to_string(get_path(puzzle)) {
+            Ok(s) => Manifest::try_from(s),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Manifest::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Set the example file of a part and the answer expected for it.
+    /// An example takes the place of the scaffolded entry of its part, if that entry has no expected answer or the same one.
+    /// Returns `false` if the example already had an expected answer, which is kept.
+    pub fn fill(&mut self, part: u8, file: &str, expected: Option<String>) -> bool {
+        let example = Example {
+            file: file.into(),
+            part,
+            expected,
+        };
+
+        if let Some(index) = self
+            .examples
+            .iter()
+            .position(|x| x.part == part && x.file == file)
+        {
+            if self.examples[index].expected.is_some() {
+                return false;
+            }
+
+            self.examples[index] = example;
+            return true;
+        }
+
+        let scaffolded = self.examples.iter().position(|x| {
+            x.part == part && (x.expected.is_none() || x.expected == example.expected)
+        });
+
+        match scaffolded {
+            Some(index) => self.examples[index] = example,
+            None => self.examples.push(example),
+        }
+
+        true
+    }
+}
+
+/// Run a part of a solution against an example file and assert that it produces the expected answer.
+pub fn check(solution: &Solution, file: &str, part: u8, expected: Option<&str>) {
+    let path = format!("data/{}/examples/{file}", solution.puzzle.year);
+    let input = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("could not read example file \"{path}\": {e}"));
+
+    let result = solution
+        .run_part(&input, part, RunMode::Silent)
+        .unwrap_or_else(|| panic!("part {part} is not enabled for this solution."));
+
+    assert_eq!(
+        result.answer.as_deref(),
+        expected,
+        "unexpected answer for part {part} of example \"{file}\"."
+    );
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Manifests are meant to be edited by hand, so they are written with one example per line and a fixed key order.
+impl Display for Manifest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{{")?;
+        writeln!(f, "  \"examples\": [")?;
+
+        for (i, example) in self.examples.iter().enumerate() {
+            let file = JsonValue::String(example.file.clone());
+            let expected = match &example.expected {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            };
+
+            writeln!(
+                f,
+                "    {{ \"file\": {}, \"part\": {}, \"expected\": {} }}{}",
+                file.stringify().map_err(|_| std::fmt::Error)?,
+                example.part,
+                expected.stringify().map_err(|_| std::fmt::Error)?,
+                if i + 1 < self.examples.len() { "," } else { "" }
+            )?;
+        }
+
+        writeln!(f, "  ]")?;
+        writeln!(f, "}}")
+    }
+}
+
+impl TryFrom<String> for Manifest {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected example manifest to be a JSON object.")?;
+
+        let examples = json
+            .get("examples")
+            .and_then(|v| v.get::<Vec<JsonValue>>())
+            .ok_or("expected example manifest to contain an `examples` array.")?;
+
+        Ok(Manifest {
+            examples: examples
+                .iter()
+                .map(Example::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl TryFrom<&JsonValue> for Example {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected example to be a JSON object.")?;
+
+        let file = json
+            .get("file")
+            .and_then(|v| v.get::<String>())
+            .cloned()
+            .ok_or("Expected example.file to be a string.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .filter(|x| **x == 1.0 || **x == 2.0)
+            .map(|x| *x as u8)
+            .ok_or("Expected example.part to be 1 or 2.")?;
+
+        let expected = match json.get("expected") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(
+                v.get::<String>()
+                    .cloned()
+                    .ok_or("Expected example.expected to be null or a string.")?,
+            ),
+        };
+
+        Ok(Example {
+            file,
+            part,
+            expected,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Example, Manifest};
+    use crate::template::PuzzleId;
+    use crate::{day, year};
+
+    fn example(file: &str, part: u8, expected: Option<&str>) -> Example {
+        Example {
+            file: file.into(),
+            part,
+            expected: expected.map(Into::into),
+        }
+    }
+
+    #[test]
+    fn parses_manifests() {
+        let json = r#"{"examples":[{"file":"01.txt","part":1,"expected":"142"},{"file":"01-2.txt","part":2,"expected":null}]}"#;
+        assert_eq!(
+            Manifest::try_from(json.to_string()).unwrap().examples,
+            vec![
+                example("01.txt", 1, Some("142")),
+                example("01-2.txt", 2, None)
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_parts() {
+        let json = r#"{"examples":[{"file":"01.txt","part":3,"expected":null}]}"#;
+        assert!(Manifest::try_from(json.to_string()).is_err());
+    }
+
+    #[test]
+    fn fills_scaffolded_examples() {
+        let mut manifest = Manifest::scaffold(PuzzleId::new(year!(2023), day!(1)), [None, None]);
+
+        assert!(manifest.fill(1, "01.txt", Some("142".into())));
+        assert!(manifest.fill(2, "01-2.txt", Some("281".into())));
+        assert!(!manifest.fill(1, "01.txt", Some("1".into())));
+        assert!(manifest.fill(1, "01-3.txt", None));
+
+        assert_eq!(
+            manifest.examples,
+            vec![
+                example("01.txt", 1, Some("142")),
+                example("01-2.txt", 2, Some("281")),
+                example("01-3.txt", 1, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn fills_examples_scaffolded_with_answers() {
+        let puzzle = PuzzleId::new(year!(2023), day!(1));
+        let mut manifest = Manifest::scaffold(puzzle, [Some("142".into()), Some("281".into())]);
+        assert_eq!(
+            manifest.examples,
+            vec![
+                example("01.txt", 1, Some("142")),
+                example("01.txt", 2, Some("281")),
+            ]
+        );
+
+        // part two has an example of its own, which replaces the scaffolded entry with the same answer.
+        assert!(!manifest.fill(1, "01.txt", Some("142".into())));
+        assert!(manifest.fill(2, "01-2.txt", Some("281".into())));
+
+        assert_eq!(
+            manifest.examples,
+            vec![
+                example("01.txt", 1, Some("142")),
+                example("01-2.txt", 2, Some("281")),
+            ]
+        );
+    }
+
+    #[test]
+    fn roundtrips_manifests() {
+        let manifest = Manifest {
+            examples: vec![example("05.txt", 1, Some("35")), example("05.txt", 2, None)],
+        };
+
+        let json = manifest.to_string();
+        assert_eq!(
+            json,
+            "{
+  \"examples\": [
+    { \"file\": \"05.txt\", \"part\": 1, \"expected\": \"35\" },
+    { \"file\": \"05.txt\", \"part\": 2, \"expected\": null }
+  ]
+}
+"
+        );
+        assert_eq!(Manifest::try_from(json).unwrap(), manifest);
+    }
+}
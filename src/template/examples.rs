@@ -0,0 +1,164 @@
+//! Extraction of the title, example inputs and their expected answers from puzzle descriptions.
+//! Works on the markdown written by `cargo download`, see [`crate::template::markdown`].
+
+/// Heading that starts the second part of a puzzle description.
+const PART_TWO_HEADING: &str = "## --- Part Two ---";
+
+/// The examples found in the description of one part of a puzzle.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct PartExamples {
+    /// Contents of all code blocks, in order of appearance. Each of them could be the example input.
+    pub blocks: Vec<String>,
+    /// The last emphasized code span, which is usually the answer for the example.
+    pub answer: Option<String>,
+}
+
+/// Split a puzzle description into its parts and collect the examples of each.
+/// The second entry is [`None`] if the description does not include part two yet.
+pub fn parse(markdown: &str) -> (PartExamples, Option<PartExamples>) {
+    match markdown.find(PART_TWO_HEADING) {
+        Some(pos) => (
+            parse_part(&markdown[..pos]),
+            Some(parse_part(&markdown[pos..])),
+        ),
+        None => (parse_part(markdown), None),
+    }
+}
+
+/// Read the title of a puzzle from the heading of its description, e.g. `Trebuchet?!` for `## --- Day 1: Trebuchet?! ---`.
+pub fn title(markdown: &str) -> Option<&str> {
+    let heading = markdown
+        .lines()
+        .find_map(|line| line.strip_prefix("## --- Day "))?;
+
+    let (_, title) = heading.trim_end_matches("---").split_once(':')?;
+    Some(title.trim()).filter(|x| !x.is_empty())
+}
+
+fn parse_part(markdown: &str) -> PartExamples {
+    let mut examples = PartExamples::default();
+    let mut block: Option<String> = None;
+
+    for line in markdown.lines() {
+        if line.starts_with("```") {
+            match block.take() {
+                Some(content) => examples.blocks.push(content),
+                None => block = Some(String::new()),
+            }
+            continue;
+        }
+
+        match block.as_mut() {
+            Some(content) => {
+                content.push_str(line);
+                content.push('\n');
+            }
+            None => {
+                if let Some(answer) = last_emphasized_code(line) {
+                    examples.answer = Some(answer);
+                }
+            }
+        }
+    }
+
+    examples
+}
+
+/// Find the last code span of a line that is emphasized, i.e. ``` `*42*` ``` or ``` *`42`* ```.
+fn last_emphasized_code(line: &str) -> Option<String> {
+    let mut answer = None;
+    let mut rest = line;
+
+    while let Some(start) = rest.find(['`', '*']) {
+        let open = &rest[start..];
+
+        let closing = if open.starts_with("`*") {
+            "*`"
+        } else if open.starts_with("*`") {
+            "`*"
+        } else {
+            rest = &rest[start + 1..];
+            continue;
+        };
+
+        let Some(len) = open[2..].find(closing) else {
+            break;
+        };
+
+        let value = open[2..2 + len].trim();
+        if !value.is_empty() {
+            answer = Some(value.to_string());
+        }
+
+        rest = &open[2 + len + closing.len()..];
+    }
+
+    answer
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::*;
+
+    const PUZZLE: &str = "## --- Day 1: Trebuchet?! ---
+
+For example:
+
+```
+1abc2
+treb7uchet
+```
+
+In this example, the calibration values of these lines are `12` and `77`. Adding these together produces `*89*`.
+
+## --- Part Two ---
+
+Your calculation isn't quite right.
+
+```
+two1nine
+```
+
+```
+eightwothree
+```
+
+Adding these together produces *`281`*.
+";
+
+    #[test]
+    fn parses_examples_per_part() {
+        let (part_one, part_two) = parse(PUZZLE);
+
+        assert_eq!(part_one.blocks, vec!["1abc2\ntreb7uchet\n"]);
+        assert_eq!(part_one.answer.as_deref(), Some("89"));
+
+        let part_two = part_two.unwrap();
+        assert_eq!(part_two.blocks, vec!["two1nine\n", "eightwothree\n"]);
+        assert_eq!(part_two.answer.as_deref(), Some("281"));
+    }
+
+    #[test]
+    fn parses_descriptions_without_part_two() {
+        let (part_one, part_two) = parse(&PUZZLE[..PUZZLE.find("## --- Part Two").unwrap()]);
+        assert_eq!(part_one.blocks.len(), 1);
+        assert_eq!(part_two, None);
+    }
+
+    #[test]
+    fn reads_titles() {
+        assert_eq!(title(PUZZLE), Some("Trebuchet?!"));
+        assert_eq!(title("## --- Part Two ---\n"), None);
+    }
+
+    #[test]
+    fn finds_last_emphasized_code() {
+        assert_eq!(
+            last_emphasized_code("`*a*` and *`b`* but not `c` or *d*"),
+            Some("b".into())
+        );
+        assert_eq!(last_emphasized_code("`12` and *emphasis*"), None);
+    }
+}
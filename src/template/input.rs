@@ -0,0 +1,348 @@
+/// Loading of input and example files, with errors that name the file and how to get it.
+use std::{
+    env,
+    error::Error,
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+use crate::template::{PuzzleId, ANSI_ITALIC, ANSI_RESET};
+
+/// An error which can be returned when reading a data file of a puzzle.
+#[derive(Debug)]
+pub enum ReadFileError {
+    NotFound {
+        path: PathBuf,
+        folder: String,
+        puzzle: PuzzleId,
+    },
+    Empty {
+        path: PathBuf,
+        folder: String,
+        puzzle: PuzzleId,
+    },
+    IO {
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl ReadFileError {
+    /// The command that creates the missing file, if there is one.
+    fn suggestion(folder: &str, puzzle: PuzzleId) -> Option<String> {
+        match folder {
+            "inputs" => Some(format!(
+                "Run `cargo download {} --year {}` to download it.",
+                puzzle.day, puzzle.year
+            )),
+            "examples" => Some(format!(
+                "Run `cargo examples {} --year {}` to extract it from the puzzle description.",
+                puzzle.day, puzzle.year
+            )),
+            _ => None,
+        }
+    }
+}
+
+impl Display for ReadFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (message, suggestion) = match self {
+            ReadFileError::NotFound {
+                path,
+                folder,
+                puzzle,
+            } => (
+                format!("could not find \"{}\".", path.display()),
+                Self::suggestion(folder, *puzzle),
+            ),
+            ReadFileError::Empty {
+                path,
+                folder,
+                puzzle,
+            } => (
+                format!("\"{}\" is empty.", path.display()),
+                Self::suggestion(folder, *puzzle),
+            ),
+            ReadFileError::IO { path, source } => (
+                format!("could not read \"{}\": {source}", path.display()),
+                None,
+            ),
+        };
+
+        match suggestion {
+            Some(suggestion) => write!(f, "{message} {suggestion}"),
+            None => write!(f, "{message}"),
+        }
+    }
+}
+
+impl Error for ReadFileError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ReadFileError::IO { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Reads a text file of a puzzle to a string. Missing and empty files are reported as errors.
+pub fn try_read_file(folder: &str, puzzle: PuzzleId) -> Result<String, ReadFileError> {
+    let path = PathBuf::from(puzzle.data_path(folder, "txt"));
+    read(path, folder, puzzle)
+}
+
+/// Reads a text file of a puzzle to a string, appending a part suffix. E.g. like `01-2.txt`.
+pub fn try_read_file_part(
+    folder: &str,
+    puzzle: PuzzleId,
+    part: u8,
+) -> Result<String, ReadFileError> {
+    let path = PathBuf::from("data")
+        .join(puzzle.year.to_string())
+        .join(folder)
+        .join(format!("{}-{part}.txt", puzzle.day));
+    read(path, folder, puzzle)
+}
+
+/// Helper function that reads a text file to a string.
+///
+/// # Panics
+/// Panics with a message naming the file if it is missing or empty, see [`try_read_file`].
+#[must_use]
+pub fn read_file(folder: &str, puzzle: PuzzleId) -> String {
+    try_read_file(folder, puzzle).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
+///
+/// # Panics
+/// Panics with a message naming the file if it is missing or empty, see [`try_read_file_part`].
+#[must_use]
+pub fn read_file_part(folder: &str, puzzle: PuzzleId, part: u8) -> String {
+    try_read_file_part(folder, puzzle, part).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Where a solution reads its input from when run via `cargo solve`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input, `data/<year>/inputs/<day>.txt`.
+    #[default]
+    Puzzle,
+    /// An example file, either the default one or one with a part suffix like `01-2.txt`.
+    Example(Option<u8>),
+    /// Any file, relative to the working directory.
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Parse the input source from the arguments `cargo solve` passes to a solution:
+    /// `--example [n]`, or `--input <path>` where `-` is stdin.
+    pub fn from_args() -> Result<Self, String> {
+        Self::parse(&env::args().collect::<Vec<_>>())
+    }
+
+    fn parse(args: &[String]) -> Result<Self, String> {
+        if let Some(index) = args.iter().position(|x| x == "--input") {
+            return match args.get(index + 1).map(String::as_str) {
+                Some("-") => Ok(InputSource::Stdin),
+                Some(path) => Ok(InputSource::Path(path.into())),
+                None => Err("`--input` expects a path, or `-` to read from stdin.".into()),
+            };
+        }
+
+        if let Some(index) = args.iter().position(|x| x == "--example") {
+            return match args.get(index + 1).filter(|x| !x.starts_with('-')) {
+                Some(n) => n
+                    .parse()
+                    .map(|n| InputSource::Example(Some(n)))
+                    .map_err(|_| {
+                        format!("`--example` expects the number of an example file, got `{n}`.")
+                    }),
+                None => Ok(InputSource::Example(None)),
+            };
+        }
+
+        Ok(InputSource::Puzzle)
+    }
+
+    /// The arguments that select this input source when passed to a solution.
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            InputSource::Puzzle => vec![],
+            InputSource::Example(None) => vec!["--example".into()],
+            InputSource::Example(Some(n)) => vec!["--example".into(), n.to_string()],
+            InputSource::Path(path) => vec!["--input".into(), path.display().to_string()],
+            InputSource::Stdin => vec!["--input".into(), "-".into()],
+        }
+    }
+
+    /// Read the input of a puzzle from this source.
+    pub fn read(&self, puzzle: PuzzleId) -> Result<String, ReadFileError> {
+        match self {
+            InputSource::Puzzle => try_read_file("inputs", puzzle),
+            InputSource::Example(None) => try_read_file("examples", puzzle),
+            InputSource::Example(Some(n)) => try_read_file_part("examples", puzzle, *n),
+            InputSource::Path(path) => read(path.clone(), "", puzzle),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|source| ReadFileError::IO {
+                        path: "<stdin>".into(),
+                        source,
+                    })?;
+
+                if input.trim().is_empty() {
+                    return Err(ReadFileError::Empty {
+                        path: "<stdin>".into(),
+                        folder: String::new(),
+                        puzzle,
+                    });
+                }
+
+                Ok(input)
+            }
+        }
+    }
+}
+
+/// Read the input of a solution binary from the source selected by its arguments, see [`InputSource::from_args`].
+/// Answers can only be submitted for the puzzle input.
+pub fn read_input_from_args(puzzle: PuzzleId) -> Result<String, String> {
+    let source = InputSource::from_args()?;
+
+    if source != InputSource::Puzzle {
+        if env::args().any(|x| x == "--submit") {
+            return Err(format!(
+                "refusing to submit answers computed from {source}, only the puzzle input can be submitted."
+            ));
+        }
+
+        println!("{ANSI_ITALIC}Using {source}{ANSI_RESET}");
+    }
+
+    source.read(puzzle).map_err(|e| e.to_string())
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Puzzle => write!(f, "puzzle input"),
+            InputSource::Example(None) => write!(f, "example"),
+            InputSource::Example(Some(n)) => write!(f, "example {n}"),
+            InputSource::Path(path) => write!(f, "\"{}\"", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+fn read(path: PathBuf, folder: &str, puzzle: PuzzleId) -> Result<String, ReadFileError> {
+    let cwd = env::current_dir().map_err(|source| ReadFileError::IO {
+        path: path.clone(),
+        source,
+    })?;
+
+    match fs::read_to_string(cwd.join(&path)) {
+        Ok(s) if s.trim().is_empty() => Err(ReadFileError::Empty {
+            path,
+            folder: folder.into(),
+            puzzle,
+        }),
+        Ok(s) => Ok(s),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(ReadFileError::NotFound {
+            path,
+            folder: folder.into(),
+            puzzle,
+        }),
+        Err(source) => Err(ReadFileError::IO { path, source }),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{try_read_file, InputSource, ReadFileError};
+    use crate::template::PuzzleId;
+    use crate::{day, year};
+
+    #[test]
+    fn reports_missing_files() {
+        let puzzle = PuzzleId::new(year!(2015), day!(25));
+        let err = try_read_file("inputs", puzzle).unwrap_err();
+
+        assert!(matches!(err, ReadFileError::NotFound { .. }));
+        assert_eq!(
+            err.to_string(),
+            "could not find \"data/2015/inputs/25.txt\". Run `cargo download 25 --year 2015` to download it."
+        );
+    }
+
+    #[test]
+    fn reports_empty_files() {
+        let puzzle = PuzzleId::new(year!(2023), day!(3));
+        let err = ReadFileError::Empty {
+            path: puzzle.data_path("examples", "txt").into(),
+            folder: "examples".into(),
+            puzzle,
+        };
+
+        assert_eq!(
+            err.to_string(),
+            "\"data/2023/examples/03.txt\" is empty. Run `cargo examples 03 --year 2023` to extract it from the puzzle description."
+        );
+    }
+
+    #[test]
+    fn reads_files() {
+        let puzzle = PuzzleId::new(year!(2023), day!(1));
+        assert!(try_read_file("examples", puzzle)
+            .unwrap()
+            .starts_with("two1nine"));
+    }
+
+    #[test]
+    fn parses_input_sources() {
+        let parse = |args: &[&str]| {
+            InputSource::parse(&args.iter().map(|x| x.to_string()).collect::<Vec<_>>())
+        };
+
+        assert_eq!(parse(&["01"]), Ok(InputSource::Puzzle));
+        assert_eq!(parse(&["01", "--example"]), Ok(InputSource::Example(None)));
+        assert_eq!(
+            parse(&["01", "--example", "--time"]),
+            Ok(InputSource::Example(None))
+        );
+        assert_eq!(
+            parse(&["01", "--example", "2"]),
+            Ok(InputSource::Example(Some(2)))
+        );
+        assert_eq!(
+            parse(&["01", "--input", "edge.txt"]),
+            Ok(InputSource::Path("edge.txt".into()))
+        );
+        assert_eq!(parse(&["01", "--input", "-"]), Ok(InputSource::Stdin));
+        assert!(parse(&["01", "--input"]).is_err());
+        assert!(parse(&["01", "--example", "two"]).is_err());
+    }
+
+    #[test]
+    fn roundtrips_input_source_args() {
+        for source in [
+            InputSource::Puzzle,
+            InputSource::Example(None),
+            InputSource::Example(Some(3)),
+            InputSource::Path("a/b.txt".into()),
+            InputSource::Stdin,
+        ] {
+            let mut args = vec!["01".to_string()];
+            args.extend(source.to_args());
+            assert_eq!(InputSource::parse(&args), Ok(source));
+        }
+    }
+}
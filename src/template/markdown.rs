@@ -0,0 +1,211 @@
+//! Minimal conversion of advent of code puzzle pages to markdown.
+//! Only handles the handful of tags used inside puzzle `<article>`s, everything else is dropped.
+
+/// Convert the `<article>` elements of a page to markdown. Pages without articles are converted as a whole.
+pub fn from_html(html: &str) -> String {
+    let articles = extract_articles(html);
+
+    let markdown = if articles.is_empty() {
+        convert(html)
+    } else {
+        articles
+            .iter()
+            .map(|article| convert(article))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    };
+
+    normalize_blank_lines(&markdown)
+}
+
+fn extract_articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(end) = rest[start..].find("</article>") else {
+            break;
+        };
+
+        articles.push(&rest[start..start + end]);
+        rest = &rest[start + end + "</article>".len()..];
+    }
+
+    articles
+}
+
+fn convert(html: &str) -> String {
+    let mut out = String::new();
+    let mut links: Vec<String> = vec![];
+    let mut in_pre = false;
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(tag_start) = rest.find('<') else {
+            push_text(&mut out, rest, in_pre);
+            break;
+        };
+
+        push_text(&mut out, &rest[..tag_start], in_pre);
+
+        let Some(tag_len) = rest[tag_start..].find('>') else {
+            break;
+        };
+
+        let tag = &rest[tag_start + 1..tag_start + tag_len];
+        rest = &rest[tag_start + tag_len + 1..];
+
+        let is_closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        // keep preformatted blocks verbatim, markup inside of them is only used for highlighting.
+        if in_pre && name != "pre" {
+            continue;
+        }
+
+        match (name.as_str(), is_closing) {
+            ("h2", false) => out.push_str("## "),
+            ("h2" | "p" | "ul", true) => out.push_str("\n\n"),
+            ("pre", false) => {
+                in_pre = true;
+                out.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            ("code", _) => out.push('`'),
+            ("em", _) => out.push('*'),
+            ("li", false) => out.push_str("- "),
+            ("li" | "br", _) => out.push('\n'),
+            ("a", false) => {
+                links.push(attribute(tag, "href").unwrap_or_default());
+                out.push('[');
+            }
+            ("a", true) => {
+                let href = links.pop().unwrap_or_default();
+                out.push_str(&format!("]({href})"));
+            }
+            _ => {}
+        }
+    }
+
+    out
+}
+
+fn push_text(out: &mut String, text: &str, in_pre: bool) {
+    let text = decode_entities(text);
+
+    if in_pre {
+        out.push_str(&text);
+    } else {
+        // whitespace in html is not significant, line breaks are produced by tags.
+        let text = text.replace('\n', " ");
+        if out.is_empty() || out.ends_with('\n') || out.ends_with("- ") {
+            out.push_str(text.trim_start());
+        } else {
+            out.push_str(&text);
+        }
+    }
+}
+
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = start + tag[start..].find('"')?;
+    Some(decode_entities(&tag[start..end]))
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+fn normalize_blank_lines(markdown: &str) -> String {
+    let mut out = String::new();
+    let mut blank_lines = 0;
+
+    for line in markdown.trim().lines() {
+        let line = line.trim_end();
+
+        if line.is_empty() {
+            blank_lines += 1;
+            if blank_lines > 1 {
+                continue;
+            }
+        } else {
+            blank_lines = 0;
+        }
+
+        out.push_str(line);
+        out.push('\n');
+    }
+
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::from_html;
+
+    #[test]
+    fn converts_puzzle_articles() {
+        let html = r#"<html><body><nav>ignored</nav><main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2><p>Something is wrong with <a href="/2023/events" target="_blank">global snow production</a>.</p>
+<p>For example:</p>
+<pre><code>1abc2
+<em>pqr3stu8vwx</em>
+</code></pre>
+<p>Adding these together produces <code><em>142</em></code>.</p>
+<ul><li>One &amp; <em>two</em></li><li>&lt;three&gt;</li></ul>
+</article>
+<p>Your puzzle answer was <code>54388</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Done.</p></article>
+</main></body></html>"#;
+
+        let expected = "## --- Day 1: Trebuchet?! ---
+
+Something is wrong with [global snow production](/2023/events).
+
+For example:
+
+```
+1abc2
+pqr3stu8vwx
+```
+
+Adding these together produces `*142*`.
+
+- One & *two*
+- <three>
+
+## --- Part Two ---
+
+Done.
+";
+
+        assert_eq!(from_html(html), expected);
+    }
+
+    #[test]
+    fn converts_pages_without_articles() {
+        assert_eq!(
+            from_html("<main><p>That's the\nright answer!</p></main>"),
+            "That's the right answer!\n"
+        );
+    }
+}
@@ -1,44 +1,42 @@
-use std::{env, fs};
-
-pub mod aoc_cli;
+pub mod aoc_client;
 pub mod commands;
+pub mod example_manifest;
+pub mod registry;
 pub mod runner;
 
 pub use day::*;
+pub use input::*;
+pub use puzzle_id::*;
+pub use solution::Solution;
+pub use year::*;
 
+mod answers;
+mod budgets;
 mod day;
+mod examples;
+mod input;
+mod markdown;
+mod puzzle_id;
 mod readme_benchmarks;
 mod run_multi;
+mod solution;
+mod submissions;
 mod timings;
+mod timings_history;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
-#[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
-}
-
-/// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
-#[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
-}
-
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `DAY`, `YEAR` and `PUZZLE` and sets up the input and runner for each part.
+/// The year is taken from the name of the solution, which must be `<year>-<day>.rs`.
+///
+/// Tests are generated for the examples listed in `data/<year>/examples/<day>.json`.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// Alternatively, it can name a type that implements [`template::Solution`](crate::template::Solution),
+/// which parses the input once for both parts.
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
@@ -50,19 +48,77 @@ macro_rules! solution {
     ($day:expr, 2) => {
         $crate::solution!(@impl $day, [part_two, 2]);
     };
+    ($day:expr, $solution:ty) => {
+        $crate::solution!(
+            @common $day,
+            [1, 2],
+            |input, parts, mode| {
+                $crate::template::runner::run_registered_solution::<$solution>(
+                    input, DAY, parts, mode,
+                )
+            },
+            |input: &str| $crate::template::runner::run_solution::<$solution>(input, PUZZLE)
+        );
+    };
 
     (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(
+            @common $day,
+            [$( $part ),*],
+            |input, parts, mode| {
+                let mut results = vec![];
+                $(
+                    if parts.contains(&$part) {
+                        results.push($crate::template::runner::run_registered_part(
+                            $func, input, DAY, $part, mode,
+                        ));
+                    }
+                )*
+                results
+            },
+            |input: &str| {
+                $( $crate::template::runner::run_part($func, input, PUZZLE, $part); )*
+            }
+        );
+    };
+
+    (@common $day:expr, [$( $part:expr ),*], $runner:expr, $main:expr) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
+        /// The year of the current day.
+        const YEAR: $crate::template::Year = $crate::template::Year::from_module_path(module_path!());
+        /// The current puzzle.
+        const PUZZLE: $crate::template::PuzzleId = $crate::template::PuzzleId::new(YEAR, DAY);
 
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
+        /// Registry entry for this day, allows the runner to call the solution in-process.
+        #[allow(dead_code)]
+        pub const SOLUTION: $crate::template::registry::Solution =
+            $crate::template::registry::Solution {
+                puzzle: PUZZLE,
+                parts: &[$( $part ),*],
+                runner: $runner,
+            };
+
+        /// Tests generated from the example manifest of the day, see `template::example_manifest`.
+        #[cfg(test)]
+        mod example_tests {
+            include!(concat!(env!("OUT_DIR"), "/examples/", env!("CARGO_BIN_NAME"), ".rs"));
+        }
+
+        #[allow(dead_code)]
         fn main() {
-            use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let input = match $crate::template::read_input_from_args(PUZZLE) {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("Error: {e}");
+                    std::process::exit(1);
+                }
+            };
+            ($main)(&input);
         }
     };
 }
@@ -0,0 +1,57 @@
+use std::fmt::Display;
+
+use crate::template::{Day, Year};
+
+/// Identifies a single puzzle, i.e. a day of a given year.
+///
+/// # Display
+/// This value displays as `<year>-<day>`, which is also the name of the puzzle's solution binary.
+///
+/// ```
+/// # use advent_of_code::template::{Day, PuzzleId, Year};
+/// let puzzle = PuzzleId::new(Year::new(2023).unwrap(), Day::new(8).unwrap());
+/// assert_eq!(puzzle.to_string(), "2023-08")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    pub const fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+
+    /// Path of a data file of the puzzle, e.g. `data/2023/inputs/01.txt`.
+    pub fn data_path(&self, folder: &str, extension: &str) -> String {
+        format!("data/{}/{folder}/{}.{extension}", self.year, self.day)
+    }
+
+    /// Path of the puzzle's solution binary, e.g. `./src/bin/2023-01.rs`.
+    pub fn bin_path(&self) -> String {
+        format!("./src/bin/{self}.rs")
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::PuzzleId;
+    use crate::{day, year};
+
+    #[test]
+    fn builds_paths() {
+        let puzzle = PuzzleId::new(year!(2023), day!(1));
+        assert_eq!(puzzle.to_string(), "2023-01");
+        assert_eq!(puzzle.data_path("inputs", "txt"), "data/2023/inputs/01.txt");
+        assert_eq!(puzzle.bin_path(), "./src/bin/2023-01.rs");
+    }
+}
@@ -1,11 +1,16 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
+/// Every year has its own table, delimited by a pair of markers that include the year.
 use std::{fs, io};
 
-use crate::template::timings::Timings;
-use crate::template::Day;
+use crate::template::timings::{PartTiming, Timings};
+use crate::template::{PuzzleId, Year};
 
-static MARKER: &str = "<!--- benchmarking table --->";
+static MARKER_PREFIX: &str = "<!--- benchmarking table";
+
+fn marker(year: Year) -> String {
+    format!("{MARKER_PREFIX} {year} --->")
+}
 
 #[derive(Debug)]
 pub enum Error {
@@ -24,13 +29,8 @@ pub struct TablePosition {
     pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -51,75 +51,154 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+fn format_cell(part: Option<PartTiming>, with_stats: bool) -> String {
+    let Some(part) = part else {
+        return "`-`".into();
+    };
+
+    let duration = part.duration();
+
+    match part.stats {
+        Some(stats) if with_stats => format!(
+            "`{duration:.1?}` (median `{:.1?}`, p95 `{:.1?}`, min `{:.1?}`, max `{:.1?}`, σ `{:.1?}`)",
+            stats.median, stats.p95, stats.min, stats.max, stats.std_dev
+        ),
+        _ => format!("`{duration:.1?}`"),
+    }
+}
+
+/// Find where to add the table of a year that is not in the readme yet: right after the last table of another year.
+fn locate_new_table(readme: &str) -> Result<usize, Error> {
+    let pos = readme
+        .rfind(MARKER_PREFIX)
+        .ok_or_else(|| Error::Parser("Could not find any benchmarking table.".into()))?;
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    let len = readme[pos..]
+        .find("-->")
+        .map(|x| x + "-->".len())
+        .ok_or_else(|| Error::Parser("Could not find end of marker.".into()))?;
+
+    Ok(pos + len)
+}
+
+fn construct_table(
+    prefix: &str,
+    year: Year,
+    timings: Timings,
+    total_millis: f64,
+    with_stats: bool,
+) -> String {
+    let header = format!("{prefix} {year} Benchmarks");
+    let marker = marker(year);
+
+    // NOTE: the parse column is only added once a day has a separate parse step, see `template::Solution`.
+    let with_parse = timings.data.iter().any(|t| t.parse.is_some());
+
+    let mut lines: Vec<String> = vec![marker.clone(), header, String::new()];
+
+    if with_parse {
+        lines.push("| Day | Parse | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---: | :---:  |".into());
+    } else {
+        lines.push("| Day | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---:  |".into());
+    }
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
+        let path = PuzzleId::new(year, timing.day).bin_path();
+        let parse_cell = if with_parse {
+            format!(" {} |", format_cell(timing.parse, with_stats))
+        } else {
+            String::new()
+        };
+
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+            "| [Day {}]({}) |{parse_cell} {} | {} |",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            format_cell(timing.part_1, with_stats),
+            format_cell(timing.part_2, with_stats)
         ));
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(marker);
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
-    s.replace_range(positions.pos_start..positions.pos_end, &table);
+fn update_content(
+    s: &mut String,
+    year: Year,
+    timings: Timings,
+    total_millis: f64,
+    with_stats: bool,
+) -> Result<(), Error> {
+    let table = construct_table("##", year, timings, total_millis, with_stats);
+
+    if s.contains(&marker(year)) {
+        let positions = locate_table(s, &marker(year))?;
+        s.replace_range(positions.pos_start..positions.pos_end, &table);
+    } else {
+        let pos = locate_new_table(s)?;
+        s.insert_str(pos, &format!("\n\n{table}"));
+    }
+
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
+/// Update the benchmark table of a year in the readme. With `with_stats`, cells include benchmark statistics where available.
+pub fn update(year: Year, timings: Timings, with_stats: bool) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, year, timings, total_millis, with_stats)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use super::{marker, update_content};
+    use crate::{
+        day, template::runner::BenchStats, template::timings::PartTiming,
+        template::timings::Timing, template::timings::Timings, template::Year, year,
+    };
+    use std::time::Duration;
+
+    const YEAR: Year = year!(2023);
+
+    fn ms(millis: f64) -> Option<PartTiming> {
+        Some(PartTiming {
+            nanos: millis * 1_000_000_f64,
+            samples: 10,
+            stats: None,
+        })
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    parse: None,
+                    part_1: ms(10.0),
+                    part_2: ms(20.0),
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    parse: None,
+                    part_1: ms(30.0),
+                    part_2: ms(40.0),
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
+                    parse: None,
+                    part_1: ms(40.0),
+                    part_2: ms(50.0),
                     total_nanos: 9e+10,
                 },
             ],
@@ -130,53 +209,105 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, YEAR, get_mock_timings(), 190.0, false).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
-        let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let mut s = format!("{} {} {}", marker(YEAR), marker(YEAR), marker(YEAR));
+        update_content(&mut s, YEAR, get_mock_timings(), 190.0, false).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
-        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        let mut s = format!("foo\nbar\n{}{}\nbaz", marker(YEAR), marker(YEAR));
+        update_content(&mut s, YEAR, get_mock_timings(), 190.0, false).unwrap();
+        assert_eq!(s.contains("## 2023 Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
-        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
-        assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
+        let mut s = format!("foo\nbar\n{}{}\nbaz", marker(YEAR), marker(YEAR));
+        update_content(&mut s, YEAR, get_mock_timings(), 190.0, false).unwrap();
+        update_content(&mut s, YEAR, get_mock_timings(), 190.0, false).unwrap();
+        assert_eq!(s.matches(&marker(YEAR)).collect::<Vec<&str>>().len(), 2);
+        assert_eq!(
+            s.matches("## 2023 Benchmarks").collect::<Vec<&str>>().len(),
+            1
+        );
+    }
+
+    #[test]
+    fn adds_table_for_new_year() {
+        let mut s = format!("foo\n{}{}\nbaz", marker(YEAR), marker(YEAR));
+        update_content(&mut s, year!(2022), get_mock_timings(), 190.0, false).unwrap();
+        update_content(&mut s, year!(2022), get_mock_timings(), 190.0, false).unwrap();
+        assert_eq!(s.matches(&marker(YEAR)).count(), 2);
+        assert_eq!(s.matches(&marker(year!(2022))).count(), 2);
+        assert!(s.contains("| [Day 1](./src/bin/2022-01.rs) | `10.0ms` | `20.0ms` |"));
+        assert!(s.find("## 2022 Benchmarks").unwrap() > s.find(&marker(YEAR)).unwrap());
+        assert!(s.ends_with("\nbaz"));
     }
 
     #[test]
     fn format_benchmarks() {
-        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", marker(YEAR), marker(YEAR));
+        update_content(&mut s, YEAR, get_mock_timings(), 190.0, false).unwrap();
         let expected = [
             "foo",
             "bar",
-            "<!--- benchmarking table --->",
-            "## Benchmarks",
+            "<!--- benchmarking table 2023 --->",
+            "## 2023 Benchmarks",
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2023-01.rs) | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/2023-02.rs) | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/2023-04.rs) | `40.0ms` | `50.0ms` |",
             "",
             "**Total: 190.00ms**",
-            "<!--- benchmarking table --->",
+            "<!--- benchmarking table 2023 --->",
             "baz",
         ]
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_benchmarks_with_parse() {
+        let mut timings = get_mock_timings();
+        timings.data[1].parse = ms(5.0);
+
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", marker(YEAR), marker(YEAR));
+        update_content(&mut s, YEAR, timings, 195.0, false).unwrap();
+        assert!(s.contains("| Day | Parse | Part 1 | Part 2 |"));
+        assert!(s.contains("| [Day 1](./src/bin/2023-01.rs) | `-` | `10.0ms` | `20.0ms` |"));
+        assert!(s.contains("| [Day 2](./src/bin/2023-02.rs) | `5.0ms` | `30.0ms` | `40.0ms` |"));
+    }
+
+    #[test]
+    fn format_benchmarks_with_stats() {
+        let mut timings = get_mock_timings();
+        timings.data[0].part_1.as_mut().unwrap().stats = Some(BenchStats {
+            median: Duration::from_micros(9900),
+            p95: Duration::from_millis(11),
+            min: Duration::from_micros(9500),
+            max: Duration::from_millis(12),
+            std_dev: Duration::from_micros(200),
+            outliers: 1,
+            first_call: None,
+            inconsistent: 0,
+        });
+
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", marker(YEAR), marker(YEAR));
+        update_content(&mut s, YEAR, timings.clone(), 190.0, true).unwrap();
+        assert!(s.contains(
+            "| [Day 1](./src/bin/2023-01.rs) | `10.0ms` (median `9.9ms`, p95 `11.0ms`, min `9.5ms`, max `12.0ms`, σ `200.0µs`) | `20.0ms` |"
+        ));
+
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", marker(YEAR), marker(YEAR));
+        update_content(&mut s, YEAR, timings, 190.0, false).unwrap();
+        assert!(s.contains("| [Day 1](./src/bin/2023-01.rs) | `10.0ms` | `20.0ms` |"));
+    }
 }
@@ -0,0 +1,53 @@
+/// Registry of solutions that can be called in-process by the runner.
+/// Entries are produced by the `solution!` macro and collected into a [`Registry`] by the main binary.
+use crate::template::runner::{PartResult, RunMode};
+use crate::template::PuzzleId;
+
+/// Runs the given parts of a solution against an input.
+/// Solutions with a separate parse step always parse, which is reported as part 0.
+pub type SolutionRunner = fn(&str, &[u8], RunMode) -> Vec<PartResult>;
+
+/// The registered solution for a puzzle.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub puzzle: PuzzleId,
+    pub parts: &'static [u8],
+    pub runner: SolutionRunner,
+}
+
+impl Solution {
+    /// Run all parts of the solution against an input, in order.
+    pub fn run(&self, input: &str, mode: RunMode) -> Vec<PartResult> {
+        (self.runner)(input, self.parts, mode)
+    }
+
+    /// Run a single part of the solution against an input. Returns `None` if the part is not enabled.
+    pub fn run_part(&self, input: &str, part: u8, mode: RunMode) -> Option<PartResult> {
+        if !self.parts.contains(&part) {
+            return None;
+        }
+
+        (self.runner)(input, &[part], mode)
+            .into_iter()
+            .find(|result| result.part == part)
+    }
+}
+
+/// A set of registered solutions, at most one per puzzle.
+#[derive(Default)]
+pub struct Registry {
+    solutions: Vec<Solution>,
+}
+
+impl Registry {
+    pub fn new(mut solutions: Vec<Solution>) -> Self {
+        solutions.sort_unstable_by_key(|s| s.puzzle);
+        solutions.dedup_by_key(|s| s.puzzle);
+        Self { solutions }
+    }
+
+    /// Returns the registered solution for a puzzle, if there is one.
+    pub fn get(&self, puzzle: PuzzleId) -> Option<&Solution> {
+        self.solutions.iter().find(|s| s.puzzle == puzzle)
+    }
+}
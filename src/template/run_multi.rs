@@ -1,40 +1,183 @@
-use std::{collections::HashSet, io};
+use std::{
+    any::Any,
+    collections::{BTreeMap, HashSet},
+    fmt::Display,
+    io,
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Mutex},
+    thread,
+    time::Duration,
+};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{try_read_file, Day, PuzzleId, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
 
 use super::{
     all_days,
-    timings::{Timing, Timings},
+    budgets::Budgets,
+    registry::{Registry, Solution},
+    runner::{print_part_result, PartResult, RunMode, PARSE_PHASE},
+    timings::{PartTiming, Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+/// Number of fresh processes an isolated timed run benches each day in.
+const ISOLATED_SAMPLES: usize = 10;
+
+/// Stack size of the threads solutions run on, matches the main thread on most platforms.
+const SOLUTION_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// How running a day ended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DayStatus {
+    /// Every part returned an answer.
+    Solved,
+    /// The day is not scaffolded, its input is missing or a part returned no answer.
+    Unsolved,
+    Panicked,
+    TimedOut,
+    CompileFailed,
+    /// The solution binary could not be run at all, e.g. because it is missing or `cargo` is not found.
+    RunFailed,
+}
+
+impl DayStatus {
+    pub const ALL: [DayStatus; 6] = [
+        DayStatus::Solved,
+        DayStatus::Unsolved,
+        DayStatus::Panicked,
+        DayStatus::TimedOut,
+        DayStatus::CompileFailed,
+        DayStatus::RunFailed,
+    ];
+
+    /// Whether the day failed to run, as opposed to simply not being solved yet.
+    pub fn is_failure(self) -> bool {
+        matches!(
+            self,
+            DayStatus::Panicked
+                | DayStatus::TimedOut
+                | DayStatus::CompileFailed
+                | DayStatus::RunFailed
+        )
+    }
+
+    /// Status of a day that ran to completion.
+    fn from_results(day: Day, results: &[PartResult]) -> Self {
+        // NOTE: the last day does not have a second part.
+        let is_solved = results.iter().any(|r| r.part != PARSE_PHASE)
+            && results.iter().all(|r| {
+                r.part == PARSE_PHASE
+                    || r.answer.is_some()
+                    || (r.part == 2 && day.into_inner() == 25)
+            });
+
+        if is_solved {
+            DayStatus::Solved
+        } else {
+            DayStatus::Unsolved
+        }
+    }
+}
+
+impl Display for DayStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DayStatus::Solved => write!(f, "Solved"),
+            DayStatus::Unsolved => write!(f, "Unsolved ✖"),
+            DayStatus::Panicked => write!(f, "Panicked"),
+            DayStatus::TimedOut => write!(f, "Timed out"),
+            DayStatus::CompileFailed => write!(f, "Failed to compile"),
+            DayStatus::RunFailed => write!(f, "Failed to run"),
+        }
+    }
+}
+
+/// Outcome of running a set of days.
+pub struct MultiRun {
+    pub statuses: Vec<(Day, DayStatus)>,
+    /// Why days that failed to run could not be run.
+    pub errors: Vec<(Day, String)>,
+    /// Only present for timed runs.
+    pub timings: Option<Timings>,
+}
 
-    let mut need_space = false;
+impl MultiRun {
+    pub fn has_failures(&self) -> bool {
+        self.statuses.iter().any(|(_, status)| status.is_failure())
+    }
+}
 
+/// Run a set of days of a year, in order. Days present in the registry are called in-process,
+/// all other days fall back to running their solution binary.
+///
+/// With more than one job, untimed runs execute days concurrently. Output is still grouped by day and
+/// printed in order. Timed runs are always serial so that days do not skew each other's benchmarks.
+///
+/// Isolated timed runs bench every sample in a fresh process of the solution binary, so that state kept
+/// between calls can not speed up later samples.
+///
+/// A day that panics, times out or does not compile is reported as such, the remaining days still run.
+/// Timeouts are taken from `budgets`, a day without one can run forever. Days with a timeout always run
+/// their solution binary, as a thread can not be stopped but a process that times out can be killed.
+#[allow(clippy::too_many_arguments)]
+pub fn run_multi(
+    registry: &Registry,
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    is_timed: bool,
+    is_isolated: bool,
+    jobs: usize,
+    budgets: &Budgets,
+) -> MultiRun {
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
+    let puzzles: Vec<PuzzleId> = all_days()
         .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+        .map(|day| PuzzleId::new(year, day))
+        .collect();
+
+    if jobs > 1 && !is_timed {
+        return run_parallel(registry, &puzzles, is_release, jobs, budgets);
+    }
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+    let mut timings: Vec<Timing> = Vec::with_capacity(puzzles.len());
+    let mut statuses = Vec::with_capacity(puzzles.len());
+    let mut errors = vec![];
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    for (i, puzzle) in puzzles.into_iter().enumerate() {
+        print_day_header(puzzle.day, i > 0);
 
-            if output.is_empty() {
-                println!("Not solved.");
+        let timeout = budgets.timeout(puzzle.day);
+        let outcome = if is_timed && is_isolated {
+            child_commands::run_solution_isolated(puzzle, is_release, ISOLATED_SAMPLES, timeout)
+        } else if let Some(solution) = registry.get(puzzle).filter(|_| timeout.is_none()) {
+            let mode = if is_timed {
+                RunMode::Bench
             } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
-            }
+                RunMode::Run
+            };
+
+            Ok(run_registered(*solution, mode))
+        } else {
+            child_commands::run_solution(puzzle, is_timed, is_release, timeout)
+        };
+
+        let (results, status) = outcome.unwrap_or_else(|e| {
+            let error = format!("Failed to run solution: {e}");
+            eprintln!("{error}");
+            errors.push((puzzle.day, error));
+            (vec![], DayStatus::RunFailed)
         });
 
-    if is_timed {
+        print_status(status, results.is_empty(), timeout);
+
+        if !results.is_empty() {
+            timings.push(timing_from_results(puzzle.day, &results));
+        }
+
+        statuses.push((puzzle.day, status));
+    }
+
+    let timings = if is_timed {
         let timings = Timings { data: timings };
         let total_millis = timings.total_millis();
         println!(
@@ -43,7 +186,305 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
         Some(timings)
     } else {
         None
+    };
+
+    MultiRun {
+        statuses,
+        errors,
+        timings,
+    }
+}
+
+pub fn print_day_header(day: Day, need_space: bool) {
+    if need_space {
+        println!();
+    }
+
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!("------");
+}
+
+/// Print how a day ended, unless its results already tell.
+fn print_status(status: DayStatus, is_empty: bool, timeout: Option<Duration>) {
+    match status {
+        DayStatus::Solved => {}
+        DayStatus::Unsolved if !is_empty => {}
+        DayStatus::Unsolved => println!("Not solved."),
+        DayStatus::Panicked => println!("Panicked."),
+        DayStatus::TimedOut => match timeout {
+            Some(timeout) => println!("Timed out after {timeout:.1?}."),
+            None => println!("Timed out."),
+        },
+        DayStatus::CompileFailed => println!("Failed to compile."),
+        DayStatus::RunFailed => println!("Failed to run."),
+    }
+}
+
+/// Print how many days ended in each status and which ones, followed by why days failed to run.
+pub fn print_summary(run: &MultiRun) {
+    println!("\n{ANSI_BOLD}Summary{ANSI_RESET}");
+    println!("-------");
+
+    for status in DayStatus::ALL {
+        let days: Vec<String> = run
+            .statuses
+            .iter()
+            .filter(|(_, x)| *x == status)
+            .map(|(day, _)| day.to_string())
+            .collect();
+
+        if !days.is_empty() {
+            println!(
+                "{:<18} {:>2}  {}",
+                format!("{status}:"),
+                days.len(),
+                days.join(" ")
+            );
+        }
+    }
+
+    if !run.errors.is_empty() {
+        println!();
+    }
+
+    for (day, error) in &run.errors {
+        println!("Day {day}: {error}");
+    }
+}
+
+fn read_input(puzzle: PuzzleId) -> Result<String, String> {
+    try_read_file("inputs", puzzle).map_err(|e| format!("Error: {e}"))
+}
+
+fn run_registered(solution: Solution, mode: RunMode) -> (Vec<PartResult>, DayStatus) {
+    let input = match read_input(solution.puzzle) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{e}");
+            return (vec![], DayStatus::Unsolved);
+        }
+    };
+
+    match run_in_thread(solution, input, mode) {
+        Ok(results) => {
+            let status = DayStatus::from_results(solution.puzzle.day, &results);
+            (results, status)
+        }
+        Err((status, _)) => (vec![], status),
+    }
+}
+
+/// Run a registered solution on a thread of its own, so that a panic does not take down the whole run.
+/// Returns the status of a day that did not finish, along with the panic message if there is one.
+fn run_in_thread(
+    solution: Solution,
+    input: String,
+    mode: RunMode,
+) -> Result<Vec<PartResult>, (DayStatus, Option<String>)> {
+    let (tx, rx) = mpsc::channel();
+
+    thread::Builder::new()
+        .stack_size(SOLUTION_STACK_SIZE)
+        .spawn(move || {
+            let result = panic::catch_unwind(AssertUnwindSafe(|| solution.run(&input, mode)));
+            tx.send(result.map_err(|e| panic_message(&*e))).unwrap();
+        })
+        .map_err(|e| (DayStatus::Panicked, Some(e.to_string())))?;
+
+    match rx.recv() {
+        Ok(Ok(results)) => Ok(results),
+        Ok(Err(message)) => Err((DayStatus::Panicked, message)),
+        Err(_) => Err((DayStatus::Panicked, None)),
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> Option<String> {
+    payload
+        .downcast_ref::<&str>()
+        .map(ToString::to_string)
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+}
+
+/// Output of a day that was run in the background, printed once it is the day's turn.
+pub struct DayOutput {
+    pub results: Vec<PartResult>,
+    pub status: DayStatus,
+    pub output: Vec<String>,
+    pub error: Option<String>,
+}
+
+impl DayOutput {
+    fn failed(status: DayStatus, error: Option<String>) -> Self {
+        DayOutput {
+            results: vec![],
+            status,
+            output: vec![],
+            error,
+        }
+    }
+
+    fn print(&self, timeout: Option<Duration>) {
+        for line in &self.output {
+            println!("{line}");
+        }
+
+        if let Some(error) = &self.error {
+            eprintln!("{error}");
+        }
+
+        for result in &self.results {
+            print_part_result(result);
+        }
+
+        print_status(self.status, self.results.is_empty(), timeout);
+    }
+}
+
+fn run_parallel(
+    registry: &Registry,
+    puzzles: &[PuzzleId],
+    is_release: bool,
+    jobs: usize,
+    budgets: &Budgets,
+) -> MultiRun {
+    let queue = Mutex::new(puzzles.iter().copied());
+    let (tx, rx) = mpsc::channel::<(PuzzleId, DayOutput)>();
+    let mut statuses = Vec::with_capacity(puzzles.len());
+    let mut errors = vec![];
+
+    // NOTE: panic messages are printed along with the output of their day instead, see `run_day_silent`.
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(puzzles.len()) {
+            let tx = tx.clone();
+            let queue = &queue;
+
+            scope.spawn(move || loop {
+                let next = queue.lock().unwrap().next();
+                let Some(puzzle) = next else {
+                    break;
+                };
+
+                let timeout = budgets.timeout(puzzle.day);
+                let output = run_day_silent(registry, puzzle, is_release, timeout);
+                tx.send((puzzle, output)).unwrap();
+            });
+        }
+
+        drop(tx);
+
+        // print finished days in order, holding back days that finish before their predecessors.
+        let mut finished: BTreeMap<PuzzleId, DayOutput> = BTreeMap::new();
+        let mut next_index = 0;
+
+        for (puzzle, output) in rx {
+            finished.insert(puzzle, output);
+
+            while let Some(output) = puzzles
+                .get(next_index)
+                .and_then(|puzzle| finished.remove(puzzle))
+            {
+                let day = puzzles[next_index].day;
+                print_day_header(day, next_index > 0);
+                output.print(budgets.timeout(day));
+                statuses.push((day, output.status));
+
+                if let (DayStatus::RunFailed, Some(error)) = (output.status, output.error) {
+                    errors.push((day, error));
+                }
+
+                next_index += 1;
+            }
+        }
+    });
+
+    panic::set_hook(default_hook);
+
+    MultiRun {
+        statuses,
+        errors,
+        timings: None,
+    }
+}
+
+/// Run a day without printing anything, collecting its results and output instead.
+pub fn run_day_silent(
+    registry: &Registry,
+    puzzle: PuzzleId,
+    is_release: bool,
+    timeout: Option<Duration>,
+) -> DayOutput {
+    // NOTE: days with a timeout run their solution binary, which is killed when it times out.
+    let Some(solution) = registry.get(puzzle).filter(|_| timeout.is_none()) else {
+        return match child_commands::run_solution_captured(puzzle, is_release, timeout) {
+            Ok((results, status, output)) => DayOutput {
+                results,
+                status,
+                output,
+                error: None,
+            },
+            Err(e) => DayOutput::failed(
+                DayStatus::RunFailed,
+                Some(format!("Failed to run solution: {e}")),
+            ),
+        };
+    };
+
+    let input = match read_input(puzzle) {
+        Ok(input) => input,
+        Err(e) => return DayOutput::failed(DayStatus::Unsolved, Some(e)),
+    };
+
+    // NOTE: a panicking day must not take down the worker, others are still queued behind it.
+    match run_in_thread(*solution, input, RunMode::Silent) {
+        Ok(results) => DayOutput {
+            status: DayStatus::from_results(puzzle.day, &results),
+            results,
+            output: vec![],
+            error: None,
+        },
+        Err((status, message)) => DayOutput::failed(
+            status,
+            message.map(|x| format!("Solution for day {} panicked: {x}", puzzle.day)),
+        ),
+    }
+}
+
+fn timing_from_results(day: Day, results: &[PartResult]) -> Timing {
+    let mut timing = Timing {
+        day,
+        parse: None,
+        part_1: None,
+        part_2: None,
+        total_nanos: 0_f64,
+    };
+
+    // NOTE: the parse step never has an answer, parts without one were not solved.
+    for result in results
+        .iter()
+        .filter(|r| r.part == PARSE_PHASE || r.answer.is_some())
+    {
+        let slot = match result.part {
+            PARSE_PHASE => &mut timing.parse,
+            1 => &mut timing.part_1,
+            2 => &mut timing.part_2,
+            _ => continue,
+        };
+
+        #[allow(clippy::cast_precision_loss)]
+        let nanos = result.duration.as_nanos() as f64;
+        timing.total_nanos += nanos;
+
+        *slot = Some(PartTiming {
+            nanos,
+            samples: result.samples,
+            stats: result.stats,
+        });
     }
+
+    timing
 }
 
 #[derive(Debug)]
@@ -52,205 +493,491 @@ pub enum Error {
     IO(io::Error),
 }
 
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::BrokenPipe => write!(f, "could not capture the output of the solution."),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
 impl From<std::io::Error> for Error {
     fn from(e: std::io::Error) -> Self {
         Error::IO(e)
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the result records they print.
 pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use super::{DayStatus, Error};
+    use crate::template::{
+        runner::{combine_runs, print_part_result, PartResult},
+        PuzzleId, ANSI_ITALIC, ANSI_RESET,
+    };
     use std::{
-        io::{BufRead, BufReader},
-        path::Path,
+        collections::HashMap,
+        io::{self, BufRead, BufReader, Read},
+        path::{Path, PathBuf},
         process::{Command, Stdio},
+        str::FromStr,
+        sync::mpsc::{self, RecvTimeoutError, Sender},
         thread,
+        time::{Duration, Instant},
     };
+    use tinyjson::JsonValue;
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+    /// A line a solution bin printed.
+    enum Line {
+        Stdout(String),
+        Stderr(String),
+    }
+
+    /// Run the solution bin for a given puzzle
+    pub fn run_solution(
+        puzzle: PuzzleId,
+        is_timed: bool,
+        is_release: bool,
+        timeout: Option<Duration>,
+    ) -> Result<(Vec<PartResult>, DayStatus), Error> {
+        let mut results = vec![];
+
+        let status = spawn_solution(
+            puzzle,
+            is_timed,
+            is_release,
+            timeout,
+            |line| match parse_record(&line) {
+                Some(result) => {
+                    print_part_result(&result);
+                    results.push(result);
+                }
+                None => println!("{line}"),
+            },
+            |line| eprintln!("{line}"),
+        )?;
+
+        let status = status.unwrap_or_else(|| DayStatus::from_results(puzzle.day, &results));
+        Ok((results, status))
+    }
+
+    /// Run the solution bin for a given puzzle without printing its output.
+    /// Returns the result records along with all other lines the solution printed, including those on stderr.
+    pub fn run_solution_captured(
+        puzzle: PuzzleId,
+        is_release: bool,
+        timeout: Option<Duration>,
+    ) -> Result<(Vec<PartResult>, DayStatus, Vec<String>), Error> {
+        let mut results = vec![];
+        let mut output = vec![];
+        let mut errors = vec![];
+
+        let status = spawn_solution(
+            puzzle,
+            false,
+            is_release,
+            timeout,
+            |line| match parse_record(&line) {
+                Some(result) => results.push(result),
+                None => output.push(line),
+            },
+            |line| errors.push(line),
+        )?;
+
+        output.extend(errors);
+
+        let status = status.unwrap_or_else(|| DayStatus::from_results(puzzle.day, &results));
+        Ok((results, status, output))
+    }
+
+    /// Run the solution bin for a given puzzle `samples` times, each in a fresh process, and combine the results.
+    /// Output other than result records is only printed for the first run.
+    /// The timeout applies to every run on its own. Benching stops at the first run that does not finish normally.
+    pub fn run_solution_isolated(
+        puzzle: PuzzleId,
+        is_release: bool,
+        samples: usize,
+        timeout: Option<Duration>,
+    ) -> Result<(Vec<PartResult>, DayStatus), Error> {
+        let mut runs = vec![];
+
+        if !Path::new(&puzzle.bin_path()).exists() {
+            return Ok((vec![], DayStatus::Unsolved));
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        if let Err(e) = super::read_input(puzzle) {
+            eprintln!("{e}");
+            return Ok((vec![], DayStatus::Unsolved));
+        }
 
-        if is_release {
-            args.push("--release");
+        let Some(executable) = build_solution(puzzle, is_release)? else {
+            return Ok((vec![], DayStatus::CompileFailed));
+        };
+
+        println!("{ANSI_ITALIC}Benching in {samples} fresh processes{ANSI_RESET}");
+
+        for i in 0..samples {
+            let mut results = vec![];
+            let deadline = timeout.map(|timeout| Instant::now() + timeout);
+
+            let status = run_executable(
+                &executable,
+                false,
+                deadline,
+                |line| match parse_record(&line) {
+                    Some(result) => results.push(result),
+                    None if i == 0 => println!("{line}"),
+                    None => {}
+                },
+                |line| eprintln!("{line}"),
+            )?;
+
+            if let Some(status) = status {
+                return Ok((vec![], status));
+            }
+
+            runs.push(results);
         }
 
+        let results = combine_runs(&runs);
+
+        for result in &results {
+            print_part_result(result);
+        }
+
+        let status = DayStatus::from_results(puzzle.day, &results);
+        Ok((results, status))
+    }
+
+    /// Build the solution bin for a given puzzle and run it, handing the lines it prints to `on_line` and `on_error`.
+    /// Returns the status of a day that did not run to completion: it does not compile, panicked or timed out.
+    /// A solution that times out is killed.
+    fn spawn_solution(
+        puzzle: PuzzleId,
+        is_timed: bool,
+        is_release: bool,
+        timeout: Option<Duration>,
+        on_line: impl FnMut(String),
+        mut on_error: impl FnMut(String),
+    ) -> Result<Option<DayStatus>, Error> {
+        // skip command invocation for days that have not been scaffolded yet.
+        if !Path::new(&puzzle.bin_path()).exists() {
+            return Ok(None);
+        }
+
+        // NOTE: the bin would exit with an error, report a missing input like for days called in-process instead.
+        if let Err(e) = super::read_input(puzzle) {
+            on_error(e);
+            return Ok(None);
+        }
+
+        // NOTE: the bin is run directly rather than through `cargo run`, so that a timeout kills the solution itself.
+        let Some(executable) = build_solution(puzzle, is_release)? else {
+            return Ok(Some(DayStatus::CompileFailed));
+        };
+
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        run_executable(&executable, is_timed, deadline, on_line, on_error)
+    }
+
+    /// Run a built solution bin, handing the lines it prints to `on_line` and `on_error`.
+    /// Returns the status of a run that did not finish normally. A solution still running at the deadline is killed.
+    fn run_executable(
+        executable: &Path,
+        is_timed: bool,
+        deadline: Option<Instant>,
+        mut on_line: impl FnMut(String),
+        mut on_error: impl FnMut(String),
+    ) -> Result<Option<DayStatus>, Error> {
+        // request machine-readable result records from the child.
+        let mut args = vec!["--format", "json"];
+
         if is_timed {
             // mirror `--time` flag to child invocations.
-            args.push("--");
             args.push("--time");
         }
 
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // both are read on threads of their own, so that waiting for a line can time out.
 
-        let mut cmd = Command::new("cargo")
+        let mut cmd = Command::new(executable)
             .args(&args)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()?;
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+        let stdout = cmd.stdout.take().ok_or(super::Error::BrokenPipe)?;
+        let stderr = cmd.stderr.take().ok_or(super::Error::BrokenPipe)?;
 
-        let mut output = vec![];
+        let (tx, rx) = mpsc::channel();
+        let stdout_thread = forward_lines(stdout, tx.clone(), Line::Stdout);
+        let stderr_thread = forward_lines(stderr, tx, Line::Stderr);
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
+        let mut is_timed_out = false;
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        loop {
+            let line = match deadline {
+                Some(deadline) => {
+                    rx.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                }
+                None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+            };
+
+            match line {
+                Ok(Line::Stdout(line)) => on_line(line),
+                Ok(Line::Stderr(line)) => on_error(line),
+                Err(RecvTimeoutError::Disconnected) => break,
+                Err(RecvTimeoutError::Timeout) => {
+                    cmd.kill()?;
+                    is_timed_out = true;
+                    break;
+                }
+            }
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        let exit_status = cmd.wait()?;
+        stdout_thread.join().unwrap();
+        stderr_thread.join().unwrap();
 
-        Ok(output)
+        if is_timed_out {
+            Ok(Some(DayStatus::TimedOut))
+        } else if exit_status.success() {
+            Ok(None)
+        } else {
+            Ok(Some(DayStatus::Panicked))
+        }
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
-
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
+    fn forward_lines(
+        reader: impl Read + Send + 'static,
+        tx: Sender<Line>,
+        to_line: fn(String) -> Line,
+    ) -> thread::JoinHandle<()> {
+        thread::spawn(move || {
+            for line in BufReader::new(reader).lines().map_while(Result::ok) {
+                // NOTE: the receiver is gone once the solution timed out.
+                if tx.send(to_line(line)).is_err() {
+                    break;
                 }
+            }
+        })
+    }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+    /// Build the solution bin for a given puzzle, forwarding compiler output to stderr.
+    /// Returns the path of the executable, or `None` if the solution does not compile.
+    fn build_solution(puzzle: PuzzleId, is_release: bool) -> Result<Option<PathBuf>, Error> {
+        let bin_name = puzzle.to_string();
+        let mut args = vec![
+            "build",
+            "--quiet",
+            "--bin",
+            &bin_name,
+            "--message-format",
+            "json-render-diagnostics",
+        ];
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+        if is_release {
+            args.push("--release");
+        }
 
-                timings.total_nanos += nanos;
-            });
+        let output = Command::new("cargo")
+            .args(&args)
+            .stderr(Stdio::inherit())
+            .output()?;
 
-        timings
-    }
+        if !output.status.success() {
+            return Ok(None);
+        }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+        // cargo prints a JSON message per line, the one for the bin lists its executable.
+        let executable = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .find_map(|line| {
+                let json = JsonValue::from_str(line).ok()?;
+                let message = json.get::<HashMap<String, JsonValue>>()?;
+                message.get("executable")?.get::<String>().cloned()
+            })
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("cargo did not report an executable for {bin_name}."),
+                )
+            })?;
+
+        Ok(Some(executable.into()))
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
-    }
+    /// Parse a line of child output as a result record.
+    /// Returns `None` for anything else the solution printed.
+    pub fn parse_record(line: &str) -> Option<PartResult> {
+        if !line.starts_with('{') {
+            return None;
+        }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+        PartResult::try_from(line).ok()
     }
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::{parse_record, run_executable, DayStatus};
+        use std::{
+            fs,
+            time::{Duration, Instant},
+        };
 
         use crate::day;
 
         #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+        fn parses_result_records() {
+            let res = parse_record(
+                r#"{"day":"01","part":2,"answer":"10","duration_nanos":74130000,"samples":99999}"#,
+            )
+            .unwrap();
+            assert_eq!(res.day, day!(1));
+            assert_eq!(res.part, 2);
+            assert_eq!(res.answer, Some("10".into()));
+            assert_eq!(res.duration, Duration::from_nanos(74_130_000));
+            assert_eq!(res.samples, 99999);
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+        fn parses_with_patterns_in_answer() {
+            let res = parse_record(
+                r#"{"day":"01","part":1,"answer":"@ @ @ ( ) ms (2s @ 5 samples)","duration_nanos":2000000000,"samples":5}"#,
+            )
+            .unwrap();
+            assert_eq!(res.answer, Some("@ @ @ ( ) ms (2s @ 5 samples)".into()));
+            assert_eq!(res.duration, Duration::from_secs(2));
         }
 
         #[test]
         fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+            let res = parse_record(
+                r#"{"day":"01","part":1,"answer":null,"duration_nanos":10,"samples":1}"#,
+            )
+            .unwrap();
+            assert_eq!(res.answer, None);
         }
+
+        #[test]
+        fn ignores_other_output() {
+            assert!(parse_record("Part 1: 0 (74.13ns @ 100000 samples)").is_none());
+            assert!(parse_record("{ debug output }").is_none());
+            assert!(parse_record("").is_none());
+        }
+
+        #[test]
+        fn roundtrips_records() {
+            let res = parse_record(
+                r#"{"day":"25","part":1,"answer":"a\nb","duration_nanos":1500,"samples":10}"#,
+            )
+            .unwrap();
+            let line = tinyjson::JsonValue::from(&res).stringify().unwrap();
+            let parsed = parse_record(&line).unwrap();
+            assert_eq!(parsed.day, day!(25));
+            assert_eq!(parsed.answer, Some("a\nb".into()));
+            assert_eq!(parsed.duration, Duration::from_nanos(1500));
+            assert_eq!(parsed.samples, 10);
+        }
+
+        #[cfg(unix)]
+        #[test]
+        fn kills_solutions_that_time_out() {
+            use std::os::unix::fs::PermissionsExt;
+
+            // stands in for a solution bin that never finishes, it ignores the arguments it is called with.
+            let path = std::env::temp_dir().join(format!("aoc-sleep-{}", std::process::id()));
+            fs::write(&path, "#!/bin/sh\nexec sleep 10\n").unwrap();
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+
+            let start = Instant::now();
+            let deadline = start + Duration::from_millis(100);
+            let status = run_executable(&path, false, Some(deadline), |_| {}, |_| {});
+            fs::remove_file(&path).unwrap();
+
+            assert_eq!(status.unwrap(), Some(DayStatus::TimedOut));
+            assert!(start.elapsed() < Duration::from_secs(5));
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{run_in_thread, DayStatus};
+    use crate::template::{
+        registry::Solution,
+        runner::{PartResult, RunMode},
+        PuzzleId,
+    };
+    use crate::{day, year};
+    use std::time::Duration;
+
+    fn result(part: u8, answer: Option<&str>) -> PartResult {
+        PartResult {
+            day: day!(1),
+            part,
+            answer: answer.map(Into::into),
+            duration: Duration::from_micros(10),
+            samples: 1,
+            stats: None,
+        }
+    }
+
+    fn solution(runner: fn(&str, &[u8], RunMode) -> Vec<PartResult>) -> Solution {
+        Solution {
+            puzzle: PuzzleId::new(year!(2023), day!(1)),
+            parts: &[1, 2],
+            runner,
+        }
+    }
+
+    #[test]
+    fn classifies_results() {
+        let solved = [result(0, None), result(1, Some("1")), result(2, Some("2"))];
+        assert_eq!(DayStatus::from_results(day!(1), &solved), DayStatus::Solved);
+
+        let unsolved = [result(1, Some("1")), result(2, None)];
+        assert_eq!(
+            DayStatus::from_results(day!(1), &unsolved),
+            DayStatus::Unsolved
+        );
+        assert_eq!(
+            DayStatus::from_results(day!(25), &unsolved),
+            DayStatus::Solved
+        );
+
+        assert_eq!(
+            DayStatus::from_results(day!(1), &[result(0, None)]),
+            DayStatus::Unsolved
+        );
+        assert_eq!(DayStatus::from_results(day!(1), &[]), DayStatus::Unsolved);
+    }
+
+    #[test]
+    fn catches_panics() {
+        let panicking = solution(|_, _, _| panic!("index out of bounds"));
+        let res = run_in_thread(panicking, String::new(), RunMode::Silent);
+        assert_eq!(
+            res.unwrap_err(),
+            (DayStatus::Panicked, Some("index out of bounds".into()))
+        );
+
+        let formatted = solution(|_, _, _| panic!("no answer for {}", 42));
+        let res = run_in_thread(formatted, String::new(), RunMode::Silent);
+        assert_eq!(
+            res.unwrap_err(),
+            (DayStatus::Panicked, Some("no answer for 42".into()))
+        );
+    }
+
+    #[test]
+    fn flags_failures() {
+        assert!(!DayStatus::Solved.is_failure());
+        assert!(!DayStatus::Unsolved.is_failure());
+        assert!(DayStatus::Panicked.is_failure());
+        assert!(DayStatus::TimedOut.is_failure());
+        assert!(DayStatus::CompileFailed.is_failure());
+        assert!(DayStatus::RunFailed.is_failure());
     }
 }
@@ -1,35 +1,280 @@
 /// Encapsulates code that interacts with solution functions.
+use std::collections::HashMap;
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{stdout, Write};
-use std::process::Output;
+use std::str::FromStr;
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
-use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use tinyjson::JsonValue;
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    let part_str = format!("Part {part}");
+use crate::template::{answers, aoc_client, submissions, Day, PuzzleId, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{Solution, ANSI_BOLD};
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+/// The phase number under which the parse step of a [`Solution`] is reported, parts are 1 and 2.
+pub const PARSE_PHASE: u8 = 0;
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+/// The result of running a single part of a solution.
+/// When a solution is called with `--format json`, one of these is printed per part as a JSON line.
+#[derive(Clone, Debug)]
+pub struct PartResult {
+    pub day: Day,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub duration: Duration,
+    pub samples: u128,
+    pub stats: Option<BenchStats>,
+}
+
+/// How a part is run when called through the solution registry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RunMode {
+    /// Run once and print the result.
+    Run,
+    /// Run once without printing anything, output is left to the caller.
+    Silent,
+    /// Bench and print the result.
+    Bench,
+}
+
+/// Summary statistics over the samples collected while benching a part.
+/// Outliers are dropped before computing these, see `summarize`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchStats {
+    pub median: Duration,
+    pub p95: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub std_dev: Duration,
+    pub outliers: u128,
+    /// Duration of the first, unbenched call. `None` for stats stored before it was recorded.
+    pub first_call: Option<Duration>,
+    /// Number of samples whose answer differed from the answer of the first call.
+    pub inconsistent: u128,
+}
+
+/// A first call that is this many times slower than the median of all samples hints at work cached between calls.
+const FIRST_CALL_SPEEDUP_FACTOR: u32 = 10;
+
+/// Cold caches easily make the first call of a fast part a few times slower, so small differences are not reported.
+const FIRST_CALL_MIN_DIFFERENCE: Duration = Duration::from_micros(100);
+
+impl BenchStats {
+    /// Signs that a solution keeps state between calls, e.g. in a global, which makes benched samples measure cached work.
+    pub fn hidden_state_warnings(&self, phase: &str, samples: u128) -> Vec<String> {
+        let mut warnings = vec![];
+
+        if self.inconsistent > 0 {
+            warnings.push(format!(
+                "{phase} returned a different answer than on its first call in {} of {samples} samples. The solution likely keeps state between calls.",
+                self.inconsistent
+            ));
+        }
+
+        if let Some(first_call) = self.first_call {
+            if first_call > self.median * FIRST_CALL_SPEEDUP_FACTOR
+                && first_call - self.median >= FIRST_CALL_MIN_DIFFERENCE
+            {
+                warnings.push(format!(
+                    "The first call of {phase} took {first_call:.1?}, while the median of all samples is {:.1?}. The solution may cache work between calls, compare with `cargo time --isolated`.",
+                    self.median
+                ));
+            }
+        }
+
+        warnings
+    }
+}
+
+pub fn run_part<I: Clone, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    puzzle: PuzzleId,
+    part: u8,
+) {
+    let is_timed = env::args().any(|x| x == "--time");
+
+    let result = if is_json_format() {
+        let (result, duration, samples, stats) =
+            run_timed(func, input, |_| {}, same_answer, is_timed);
+
+        let record = PartResult {
+            day: puzzle.day,
+            part,
+            answer: result.as_ref().map(ToString::to_string),
+            duration,
+            samples,
+            stats,
+        };
+
+        println!("{}", JsonValue::from(&record).stringify().unwrap());
+        result
+    } else {
+        run_and_print(func, input, part, is_timed).0
+    };
 
     if let Some(result) = result {
-        submit_result(result, day, part);
+        submit_result(result, puzzle, part);
+    }
+}
+
+/// Run a solution part from the solution registry and return its result.
+/// Unless silent, output is printed the same way as for `run_part`, but answers are never submitted.
+pub fn run_registered_part<I: Clone, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    day: Day,
+    part: u8,
+    mode: RunMode,
+) -> PartResult {
+    let (result, duration, samples, stats) = match mode {
+        RunMode::Run => run_and_print(func, input, part, false),
+        RunMode::Bench => run_and_print(func, input, part, true),
+        RunMode::Silent => run_timed(func, input, |_| {}, same_answer, false),
+    };
+
+    PartResult {
+        day,
+        part,
+        answer: result.map(|x| x.to_string()),
+        duration,
+        samples,
+        stats,
+    }
+}
+
+/// Run a solution that implements [`Solution`]: the input is parsed once and shared by both parts.
+/// Parsing is timed as a phase of its own, see [`PARSE_PHASE`].
+pub fn run_solution<S: Solution>(input: &str, puzzle: PuzzleId) {
+    let is_timed = env::args().any(|x| x == "--time");
+
+    let parsed = if is_json_format() {
+        let (parsed, duration, samples, stats) =
+            run_timed(S::parse, input, |_| {}, |_, _| true, is_timed);
+
+        let record = PartResult {
+            day: puzzle.day,
+            part: PARSE_PHASE,
+            answer: None,
+            duration,
+            samples,
+            stats,
+        };
+
+        println!("{}", JsonValue::from(&record).stringify().unwrap());
+        parsed
+    } else {
+        run_and_print_parse(S::parse, input, is_timed).0
+    };
+
+    run_part(S::part_one, &parsed, puzzle, 1);
+    run_part(S::part_two, &parsed, puzzle, 2);
+}
+
+/// Run a [`Solution`] from the solution registry and return the results of the parse step and the given parts.
+pub fn run_registered_solution<S: Solution>(
+    input: &str,
+    day: Day,
+    parts: &[u8],
+    mode: RunMode,
+) -> Vec<PartResult> {
+    let (parsed, duration, samples, stats) = match mode {
+        RunMode::Run => run_and_print_parse(S::parse, input, false),
+        RunMode::Bench => run_and_print_parse(S::parse, input, true),
+        RunMode::Silent => run_timed(S::parse, input, |_| {}, |_, _| true, false),
+    };
+
+    let mut results = vec![PartResult {
+        day,
+        part: PARSE_PHASE,
+        answer: None,
+        duration,
+        samples,
+        stats,
+    }];
+
+    if parts.contains(&1) {
+        results.push(run_registered_part(S::part_one, &parsed, day, 1, mode));
+    }
+
+    if parts.contains(&2) {
+        results.push(run_registered_part(S::part_two, &parsed, day, 2, mode));
     }
+
+    results
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+fn run_and_print<I: Clone, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    part: u8,
+    is_timed: bool,
+) -> (Option<T>, Duration, u128, Option<BenchStats>) {
+    let part_str = phase_name(part);
+
+    let (result, duration, samples, stats) = run_timed(
+        func,
+        input,
+        |result| {
+            print_result(result, &part_str, "");
+            if is_timed {
+                print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+                let _ = stdout().flush();
+            }
+        },
+        same_answer,
+        is_timed,
+    );
+
+    print_result(&result, &part_str, &format_duration(&duration, samples));
+    print_bench_warnings(&part_str, samples, stats.as_ref());
+
+    (result, duration, samples, stats)
+}
+
+fn run_and_print_parse<T>(
+    func: impl Fn(&str) -> T,
+    input: &str,
+    is_timed: bool,
+) -> (T, Duration, u128, Option<BenchStats>) {
+    let (parsed, duration, samples, stats) = run_timed(
+        func,
+        input,
+        |_| {
+            print_parse_result("");
+            if is_timed {
+                print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+                let _ = stdout().flush();
+            }
+        },
+        // NOTE: parsed inputs can not be compared, the answers of the parts are checked instead.
+        |_, _| true,
+        is_timed,
+    );
+
+    print_parse_result(&format_duration(&duration, samples));
+    print_bench_warnings(&phase_name(PARSE_PHASE), samples, stats.as_ref());
+
+    (parsed, duration, samples, stats)
+}
+
+/// Whether two calls of a part gave the same answer.
+fn same_answer<T: Display>(a: &Option<T>, b: &Option<T>) -> bool {
+    a.as_ref().map(ToString::to_string) == b.as_ref().map(ToString::to_string)
+}
+
+/// Run a solution part. The behavior differs depending on whether the run is timed:
+///  1. if not, the function is executed once.
+///  2. if it is, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///     Every sample is compared to the first result with `is_same`, see [`BenchStats::hidden_state_warnings`].
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+    is_same: impl Fn(&T, &T) -> bool,
+    is_timed: bool,
+) -> (T, Duration, u128, Option<BenchStats>) {
     let timer = Instant::now();
     let result = {
         let input = input.clone();
@@ -43,44 +288,114 @@ fn run_timed<I: Clone, T>(
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    if is_timed {
+        let (duration, samples, mut stats) =
+            bench(func, input, &base_time, |x| is_same(&result, x));
+        stats.first_call = Some(base_time);
+        (result, duration, samples, Some(stats))
     } else {
-        (base_time, 1)
-    };
-
-    (result, run.0, run.1)
+        (result, base_time, 1, None)
+    }
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+/// Bench a function after a number of warmup iterations.
+/// Returns the mean duration of all samples that are not outliers, along with statistics about them.
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    is_consistent: impl Fn(&T) -> bool,
+) -> (Duration, u128, BenchStats) {
+    let bench_iterations =
+        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+    // warm up caches and branch predictors, results are discarded.
+    let warmup_iterations = (bench_iterations / 10).clamp(1, 100);
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
-    );
+    for _ in 0..warmup_iterations {
+        black_box(func(black_box(input.clone())));
+    }
 
     let mut timers: Vec<Duration> = vec![];
+    let mut inconsistent = 0;
 
     for _ in 0..bench_iterations {
         // need a clone here to make the borrow checker happy.
         let cloned = input.clone();
         let timer = Instant::now();
-        black_box(func(black_box(cloned)));
+        let result = black_box(func(black_box(cloned)));
         timers.push(timer.elapsed());
+
+        if !is_consistent(&result) {
+            inconsistent += 1;
+        }
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    let (mean, mut stats) = summarize(&timers);
+    stats.inconsistent = inconsistent;
+    (mean, bench_iterations, stats)
+}
+
+/// Drop samples outside of Tukey's fences (1.5 × IQR), then compute the mean and statistics of the remaining samples.
+fn summarize(timers: &[Duration]) -> (Duration, BenchStats) {
+    let mut sorted = timers.to_vec();
+    sorted.sort_unstable();
+
+    let q1 = percentile(&sorted, 0.25).as_nanos();
+    let q3 = percentile(&sorted, 0.75).as_nanos();
+    let fence = (q3 - q1) * 3 / 2;
+    let range = q1.saturating_sub(fence)..=q3 + fence;
+
+    let filtered: Vec<Duration> = sorted
+        .iter()
+        .filter(|x| range.contains(&x.as_nanos()))
+        .copied()
+        .collect();
+
+    #[allow(clippy::cast_possible_truncation)]
+    let mean = average_duration(&filtered) as u64;
+
+    #[allow(clippy::cast_precision_loss)]
+    let variance = filtered
+        .iter()
+        .map(|x| (x.as_nanos() as f64 - mean as f64).powi(2))
+        .sum::<f64>()
+        / filtered.len() as f64;
+
+    let stats = BenchStats {
+        median: median(&filtered),
+        p95: percentile(&filtered, 0.95),
+        min: filtered[0],
+        max: filtered[filtered.len() - 1],
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        std_dev: Duration::from_nanos(variance.sqrt().round() as u64),
+        outliers: (sorted.len() - filtered.len()) as u128,
+        first_call: None,
+        inconsistent: 0,
+    };
+
+    (Duration::from_nanos(mean), stats)
+}
+
+/// Nearest-rank percentile of a sorted, non-empty slice.
+fn percentile(sorted: &[Duration], p: f64) -> Duration {
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    let rank = (p * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Median of a sorted, non-empty slice.
+fn median(sorted: &[Duration]) -> Duration {
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2
+    } else {
+        sorted[mid]
+    }
 }
 
 fn average_duration(numbers: &[Duration]) -> u128 {
@@ -99,6 +414,83 @@ fn format_duration(duration: &Duration, samples: u128) -> String {
     }
 }
 
+/// The name a phase of a solution is displayed with, e.g. `Parse` or `Part 1`.
+pub fn phase_name(part: u8) -> String {
+    if part == PARSE_PHASE {
+        "Parse".into()
+    } else {
+        format!("Part {part}")
+    }
+}
+
+/// Print the final output line(s) for a part, in the same format as `run_part`.
+pub fn print_part_result(result: &PartResult) {
+    let duration_str = format_duration(&result.duration, result.samples);
+
+    if result.part == PARSE_PHASE {
+        print_parse_result(&duration_str);
+    } else {
+        print_result(&result.answer, &phase_name(result.part), &duration_str);
+    }
+
+    print_bench_warnings(
+        &phase_name(result.part),
+        result.samples,
+        result.stats.as_ref(),
+    );
+}
+
+fn print_bench_warnings(phase: &str, samples: u128, stats: Option<&BenchStats>) {
+    for warning in stats.map_or(vec![], |x| x.hidden_state_warnings(phase, samples)) {
+        eprintln!("{ANSI_BOLD}Warning:{ANSI_RESET} {warning}");
+    }
+}
+
+/// Combine the results of several untimed runs of a solution, e.g. in fresh processes, into one result per phase.
+/// Every run contributes a single sample to each phase, answers are compared to the ones of the first run.
+pub fn combine_runs(runs: &[Vec<PartResult>]) -> Vec<PartResult> {
+    let Some(first_run) = runs.first() else {
+        return vec![];
+    };
+
+    first_run
+        .iter()
+        .map(|first| {
+            let samples: Vec<&PartResult> = runs
+                .iter()
+                .filter_map(|run| run.iter().find(|x| x.part == first.part))
+                .collect();
+
+            let timers: Vec<Duration> = samples.iter().map(|x| x.duration).collect();
+            let (mean, mut stats) = summarize(&timers);
+            stats.first_call = Some(first.duration);
+            stats.inconsistent =
+                samples.iter().filter(|x| x.answer != first.answer).count() as u128;
+
+            PartResult {
+                day: first.day,
+                part: first.part,
+                answer: first.answer.clone(),
+                duration: mean,
+                samples: timers.len() as u128,
+                stats: Some(stats),
+            }
+        })
+        .collect()
+}
+
+/// The parse step has no answer to show, only its duration.
+fn print_parse_result(duration_str: &str) {
+    let str = format!("{}: ✔{duration_str}", phase_name(PARSE_PHASE));
+
+    if duration_str.is_empty() {
+        print!("{str}");
+    } else {
+        print!("\r");
+        println!("{str}");
+    }
+}
+
 fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
@@ -134,14 +526,22 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
     }
 }
 
+/// Check whether the solution was called with `--format json`.
+fn is_json_format() -> bool {
+    let args: Vec<String> = env::args().collect();
+    args.windows(2)
+        .any(|pair| pair[0] == "--format" && pair[1] == "json")
+}
+
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
+///  2. a session cookie is configured.
+///  3. the answer is not known to be wrong from previous submissions.
 fn submit_result<T: Display>(
     result: T,
-    day: Day,
+    puzzle: PuzzleId,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+) -> Option<Result<submissions::Outcome, aoc_client::AocClientError>> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
@@ -164,11 +564,320 @@ fn submit_result<T: Display>(
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+    let answer = result.to_string();
+
+    if let Err(refusal) = submissions::check(&submissions::read_for_part(puzzle, part), &answer) {
+        eprintln!("Refusing to submit {answer}. {refusal}");
         process::exit(1);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    println!("Submitting result...");
+    let response = aoc_client::submit(puzzle, part, &answer);
+
+    if let Err(e) = &response {
+        eprintln!("Failed to submit result: {e}");
+    }
+
+    let outcome = response.map(|response| {
+        println!("{response}");
+
+        let outcome = submissions::Outcome::parse(&response);
+        println!("{outcome}");
+
+        let submission = submissions::Submission::new(puzzle.day, part, &answer, outcome);
+        if let Err(e) = submissions::append(puzzle.year, &submission) {
+            eprintln!("Failed to log submission: {e}");
+        }
+
+        if outcome == submissions::Outcome::Correct {
+            match answers::record(puzzle, part, &answer) {
+                Ok(()) => println!("Recorded answer in \"{}\".", answers::get_path(puzzle.year)),
+                Err(e) => eprintln!("Failed to record answer: {e}"),
+            }
+        }
+
+        outcome
+    });
+
+    Some(outcome)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartResult> for JsonValue {
+    fn from(value: &PartResult) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+
+        #[allow(clippy::cast_precision_loss)]
+        let duration_nanos = value.duration.as_nanos() as f64;
+        map.insert("duration_nanos".into(), JsonValue::Number(duration_nanos));
+
+        #[allow(clippy::cast_precision_loss)]
+        let samples = value.samples as f64;
+        map.insert("samples".into(), JsonValue::Number(samples));
+
+        map.insert(
+            "stats".into(),
+            match &value.stats {
+                Some(x) => JsonValue::from(x),
+                None => JsonValue::Null,
+            },
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl From<&BenchStats> for JsonValue {
+    fn from(value: &BenchStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        #[allow(clippy::cast_precision_loss)]
+        let mut insert_nanos = |key: &str, duration: Duration| {
+            map.insert(key.into(), JsonValue::Number(duration.as_nanos() as f64));
+        };
+
+        insert_nanos("median_nanos", value.median);
+        insert_nanos("p95_nanos", value.p95);
+        insert_nanos("min_nanos", value.min);
+        insert_nanos("max_nanos", value.max);
+        insert_nanos("std_dev_nanos", value.std_dev);
+
+        #[allow(clippy::cast_precision_loss)]
+        let outliers = value.outliers as f64;
+        map.insert("outliers".into(), JsonValue::Number(outliers));
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "first_call_nanos".into(),
+            match value.first_call {
+                Some(x) => JsonValue::Number(x.as_nanos() as f64),
+                None => JsonValue::Null,
+            },
+        );
+
+        #[allow(clippy::cast_precision_loss)]
+        let inconsistent = value.inconsistent as f64;
+        map.insert("inconsistent".into(), JsonValue::Number(inconsistent));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected stats to be a JSON object.")?;
+
+        let get_number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected stats.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let get_duration = |key: &str| get_number(key).map(|x| Duration::from_nanos(x as u64));
+
+        // NOTE: isolated runs do not have a first call, the key is null for them.
+        let first_call = match json.get("first_call_nanos") {
+            Some(v) if v.is_null() => None,
+            _ => Some(get_duration("first_call_nanos")?),
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(BenchStats {
+            median: get_duration("median_nanos")?,
+            p95: get_duration("p95_nanos")?,
+            min: get_duration("min_nanos")?,
+            max: get_duration("max_nanos")?,
+            std_dev: get_duration("std_dev_nanos")?,
+            outliers: get_number("outliers")? as u128,
+            first_call,
+            inconsistent: get_number("inconsistent")? as u128,
+        })
+    }
+}
+
+impl TryFrom<&str> for PartResult {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(value).or(Err("not valid JSON."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part result to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected result.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected result.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected result.answer to be null or string.")?;
+
+        let duration_nanos = json
+            .get("duration_nanos")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected result.duration_nanos to be a number.")?;
+
+        let samples = json
+            .get("samples")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected result.samples to be a number.")?;
+
+        let stats = match json.get("stats") {
+            Some(v) if !v.is_null() => Some(BenchStats::try_from(v)?),
+            _ => None,
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(PartResult {
+            day,
+            part: *part as u8,
+            answer: answer.cloned(),
+            duration: Duration::from_nanos(*duration_nanos as u64),
+            samples: *samples as u128,
+            stats,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{combine_runs, median, percentile, summarize, BenchStats, PartResult};
+    use crate::day;
+    use std::{str::FromStr, time::Duration};
+    use tinyjson::JsonValue;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|x| Duration::from_nanos(*x)).collect()
+    }
+
+    #[test]
+    fn computes_median() {
+        assert_eq!(median(&nanos(&[1, 2, 3])), Duration::from_nanos(2));
+        assert_eq!(median(&nanos(&[1, 2, 3, 4])), Duration::from_nanos(2));
+        assert_eq!(median(&nanos(&[10, 20])), Duration::from_nanos(15));
+    }
+
+    #[test]
+    fn computes_percentiles() {
+        let sorted = nanos(&(1..=100).collect::<Vec<_>>());
+        assert_eq!(percentile(&sorted, 0.95), Duration::from_nanos(95));
+        assert_eq!(percentile(&sorted, 0.0), Duration::from_nanos(1));
+        assert_eq!(percentile(&sorted, 1.0), Duration::from_nanos(100));
+    }
+
+    #[test]
+    fn summarizes_samples() {
+        let (mean, stats) = summarize(&nanos(&[12, 10, 14, 10, 14, 12]));
+        assert_eq!(mean, Duration::from_nanos(12));
+        assert_eq!(stats.median, Duration::from_nanos(12));
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.max, Duration::from_nanos(14));
+        assert_eq!(stats.std_dev, Duration::from_nanos(2));
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn detects_hidden_state() {
+        let (_, mut stats) = summarize(&nanos(&[10_000, 11_000, 10_000]));
+        assert!(stats.hidden_state_warnings("Part 1", 3).is_empty());
+
+        stats.first_call = Some(Duration::from_millis(50));
+        assert_eq!(stats.hidden_state_warnings("Part 1", 3).len(), 1);
+
+        stats.inconsistent = 2;
+        assert_eq!(stats.hidden_state_warnings("Part 1", 3).len(), 2);
+
+        // fast parts are not flagged for a slow first call, cold caches explain that.
+        let (_, mut stats) = summarize(&nanos(&[100, 110, 100]));
+        stats.first_call = Some(Duration::from_micros(50));
+        assert!(stats.hidden_state_warnings("Part 1", 3).is_empty());
+    }
+
+    #[test]
+    fn combines_runs() {
+        let result = |part: u8, answer: &str, micros: u64| PartResult {
+            day: day!(22),
+            part,
+            answer: Some(answer.into()),
+            duration: Duration::from_micros(micros),
+            samples: 1,
+            stats: None,
+        };
+
+        let runs = vec![
+            vec![result(1, "5", 30), result(2, "7", 10)],
+            vec![result(1, "5", 10), result(2, "8", 20)],
+            vec![result(1, "5", 20), result(2, "7", 30)],
+        ];
+
+        let combined = combine_runs(&runs);
+        assert_eq!(combined.len(), 2);
+        assert_eq!(combined[0].answer, Some("5".into()));
+        assert_eq!(combined[0].duration, Duration::from_micros(20));
+        assert_eq!(combined[0].samples, 3);
+
+        let stats = combined[0].stats.unwrap();
+        assert_eq!(stats.first_call, Some(Duration::from_micros(30)));
+        assert_eq!(stats.inconsistent, 0);
+        assert_eq!(combined[1].stats.unwrap().inconsistent, 1);
+    }
+
+    #[test]
+    fn drops_outliers() {
+        let (mean, stats) = summarize(&nanos(&[10, 11, 10, 12, 11, 10, 5000]));
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.max, Duration::from_nanos(12));
+        assert_eq!(mean, Duration::from_nanos(10));
+    }
+
+    #[test]
+    fn roundtrips_stats() {
+        let (_, mut stats) = summarize(&nanos(&[12, 10, 14, 10, 14, 12]));
+        stats.first_call = Some(Duration::from_nanos(40));
+        stats.inconsistent = 1;
+
+        let json = JsonValue::from(&stats);
+        assert_eq!(BenchStats::try_from(&json).unwrap(), stats);
+
+        stats.first_call = None;
+        let json = JsonValue::from(&stats);
+        assert_eq!(BenchStats::try_from(&json).unwrap(), stats);
+    }
+
+    #[test]
+    fn rejects_stats_without_hidden_state_checks() {
+        let json = JsonValue::from_str(
+            r#"{ "median_nanos": 1, "p95_nanos": 1, "min_nanos": 1, "max_nanos": 1, "std_dev_nanos": 0, "outliers": 0 }"#,
+        )
+        .unwrap();
+        assert!(BenchStats::try_from(&json).is_err());
+    }
 }
@@ -0,0 +1,38 @@
+use std::fmt::Display;
+
+/// Optional interface for solutions that parse their input once and share the result between both parts.
+/// Register an implementation with `solution!(<day>, <type>)`, parsing is then timed separately from the parts.
+///
+/// ```ignore
+/// advent_of_code::solution!(5, Day05);
+///
+/// struct Day05;
+///
+/// impl advent_of_code::template::Solution for Day05 {
+///     type Parsed = Vec<u32>;
+///     type Answer = u32;
+///
+///     fn parse(input: &str) -> Self::Parsed {
+///         input.lines().map(|line| line.parse().unwrap()).collect()
+///     }
+///
+///     fn part_one(parsed: &Self::Parsed) -> Option<Self::Answer> {
+///         parsed.iter().max().copied()
+///     }
+/// }
+/// ```
+pub trait Solution {
+    /// The parsed puzzle input.
+    type Parsed;
+    /// The type of the answers of both parts.
+    type Answer: Display;
+
+    fn parse(input: &str) -> Self::Parsed;
+
+    fn part_one(parsed: &Self::Parsed) -> Option<Self::Answer>;
+
+    /// Defaults to no answer, e.g. for the last day which does not have a second part.
+    fn part_two(_parsed: &Self::Parsed) -> Option<Self::Answer> {
+        None
+    }
+}
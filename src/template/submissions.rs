@@ -0,0 +1,444 @@
+/// Log of answers submitted to advent of code, along with how they were judged.
+/// Used to refuse answers that are known to be wrong before they are submitted again.
+/// Each line of the log file is a JSON object for a single attempt.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::{Day, PuzzleId, Year};
+
+fn get_path(year: Year) -> String {
+    format!("./data/{year}/submissions.jsonl")
+}
+
+/// How advent of code judged a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently. Holds the time left to wait, if the response stated it.
+    RateLimited(Option<Duration>),
+    AlreadySolved,
+    /// The response could not be classified.
+    Unknown,
+}
+
+impl Outcome {
+    /// Classify the answer page returned by [`aoc_client::submit`](super::aoc_client::submit).
+    pub fn parse(response: &str) -> Self {
+        if response.contains("That's the right answer") {
+            Outcome::Correct
+        } else if response.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if response.contains("your answer is too low") {
+            Outcome::TooLow
+        } else if response.contains("That's not the right answer") {
+            Outcome::Incorrect
+        } else if response.contains("You gave an answer too recently") {
+            Outcome::RateLimited(parse_wait_time(response))
+        } else if response.contains("Did you already complete it") {
+            Outcome::AlreadySolved
+        } else {
+            Outcome::Unknown
+        }
+    }
+
+    fn key(self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::Incorrect => "incorrect",
+            Outcome::TooHigh => "too_high",
+            Outcome::TooLow => "too_low",
+            Outcome::RateLimited(_) => "rate_limited",
+            Outcome::AlreadySolved => "already_solved",
+            Outcome::Unknown => "unknown",
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "correct" => Some(Outcome::Correct),
+            "incorrect" => Some(Outcome::Incorrect),
+            "too_high" => Some(Outcome::TooHigh),
+            "too_low" => Some(Outcome::TooLow),
+            "rate_limited" => Some(Outcome::RateLimited(None)),
+            "already_solved" => Some(Outcome::AlreadySolved),
+            "unknown" => Some(Outcome::Unknown),
+            _ => None,
+        }
+    }
+
+    fn is_wrong(self) -> bool {
+        matches!(
+            self,
+            Outcome::Incorrect | Outcome::TooHigh | Outcome::TooLow
+        )
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "Correct answer."),
+            Outcome::Incorrect => write!(f, "Wrong answer."),
+            Outcome::TooHigh => write!(f, "Wrong answer, too high."),
+            Outcome::TooLow => write!(f, "Wrong answer, too low."),
+            Outcome::RateLimited(Some(wait)) => {
+                write!(f, "Rate limited, try again in {}s.", wait.as_secs())
+            }
+            Outcome::RateLimited(None) => write!(f, "Rate limited, try again later."),
+            Outcome::AlreadySolved => write!(f, "Part is already solved."),
+            Outcome::Unknown => write!(f, "Could not classify the response."),
+        }
+    }
+}
+
+/// Parse the wait time from a rate limit response, e.g. `You have 1m 5s left to wait.`
+fn parse_wait_time(response: &str) -> Option<Duration> {
+    let start = response.find("You have ")? + "You have ".len();
+    let end = start + response[start..].find(" left to wait")?;
+
+    let mut secs = 0;
+    for token in response[start..end].split_whitespace() {
+        // NOTE: the token may end in any character, splitting at a byte offset could panic.
+        let (index, unit) = token.char_indices().last()?;
+        let value: u64 = token[..index].parse().ok()?;
+        secs += match unit {
+            'h' => value * 3600,
+            'm' => value * 60,
+            's' => value,
+            _ => return None,
+        };
+    }
+
+    Some(Duration::from_secs(secs))
+}
+
+/// A single submitted answer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Submission {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+impl Submission {
+    pub fn new(day: Day, part: u8, answer: &str, outcome: Outcome) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |x| x.as_secs());
+
+        Submission {
+            timestamp,
+            day,
+            part,
+            answer: answer.into(),
+            outcome,
+        }
+    }
+}
+
+/// Reason an answer is refused before it is submitted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    /// The exact answer was submitted before and judged wrong.
+    KnownWrong(Outcome),
+    /// The answer is at or above an answer that was judged too high.
+    AboveBound(i128),
+    /// The answer is at or below an answer that was judged too low.
+    BelowBound(i128),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::KnownWrong(outcome) => {
+                write!(f, "This answer was submitted before: {outcome}")
+            }
+            Refusal::AboveBound(bound) => {
+                write!(
+                    f,
+                    "The answer must be lower than {bound}, which was too high."
+                )
+            }
+            Refusal::BelowBound(bound) => {
+                write!(
+                    f,
+                    "The answer must be higher than {bound}, which was too low."
+                )
+            }
+        }
+    }
+}
+
+/// Check an answer against previous attempts for the same part.
+pub fn check(attempts: &[Submission], answer: &str) -> Result<(), Refusal> {
+    if let Some(attempt) = attempts
+        .iter()
+        .find(|a| a.answer == answer && a.outcome.is_wrong())
+    {
+        return Err(Refusal::KnownWrong(attempt.outcome));
+    }
+
+    // bounds only apply to numeric answers.
+    let Ok(value) = answer.parse::<i128>() else {
+        return Ok(());
+    };
+
+    let bound = |outcome: Outcome| {
+        attempts
+            .iter()
+            .filter(move |a| a.outcome == outcome)
+            .filter_map(|a| a.answer.parse::<i128>().ok())
+    };
+
+    if let Some(upper) = bound(Outcome::TooHigh).min() {
+        if value >= upper {
+            return Err(Refusal::AboveBound(upper));
+        }
+    }
+
+    if let Some(lower) = bound(Outcome::TooLow).max() {
+        if value <= lower {
+            return Err(Refusal::BelowBound(lower));
+        }
+    }
+
+    Ok(())
+}
+
+/// Append an attempt to the submissions log of a year.
+pub fn append(year: Year, submission: &Submission) -> Result<(), io::Error> {
+    let line = JsonValue::from(submission)
+        .stringify()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(get_path(year))?;
+
+    writeln!(file, "{line}")
+}
+
+/// Read all attempts for a part, oldest first. If the log file is not present, returns no attempts.
+pub fn read_for_part(puzzle: PuzzleId, part: u8) -> Vec<Submission> {
+    let Ok(contents) = fs::read_to_string(get_path(puzzle.year)) else {
+        return vec![];
+    };
+
+    parse_submissions(&contents)
+        .into_iter()
+        .filter(|s| s.day == puzzle.day && s.part == part)
+        .collect()
+}
+
+fn parse_submissions(contents: &str) -> Vec<Submission> {
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match Submission::try_from(line) {
+            Ok(submission) => Some(submission),
+            Err(e) => {
+                eprintln!("Skipping malformed submission: {e}");
+                None
+            }
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        #[allow(clippy::cast_precision_loss)]
+        let timestamp = value.timestamp as f64;
+        map.insert("timestamp".into(), JsonValue::Number(timestamp));
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "outcome".into(),
+            JsonValue::String(value.outcome.key().into()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&str> for Submission {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(value).or(Err("not valid JSON."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u64)
+            .ok_or("Expected submission.timestamp to be a number.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected submission.day to be a Day struct.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u8)
+            .ok_or("Expected submission.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let outcome = json
+            .get("outcome")
+            .and_then(|v| v.get::<String>())
+            .and_then(|x| Outcome::from_key(x))
+            .ok_or("Expected submission.outcome to be a known outcome.")?;
+
+        Ok(Submission {
+            timestamp,
+            day,
+            part,
+            answer: answer.clone(),
+            outcome,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check, parse_submissions, Outcome, Refusal, Submission};
+    use crate::day;
+    use std::time::Duration;
+    use tinyjson::JsonValue;
+
+    fn attempt(answer: &str, outcome: Outcome) -> Submission {
+        Submission {
+            timestamp: 0,
+            day: day!(1),
+            part: 1,
+            answer: answer.into(),
+            outcome,
+        }
+    }
+
+    #[test]
+    fn classifies_responses() {
+        assert_eq!(
+            Outcome::parse("That's the right answer! You are one gold star closer."),
+            Outcome::Correct
+        );
+        assert_eq!(
+            Outcome::parse("That's not the right answer; your answer is too high."),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            Outcome::parse("That's not the right answer; your answer is too low."),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            Outcome::parse("That's not the right answer. If you're stuck, ..."),
+            Outcome::Incorrect
+        );
+        assert_eq!(
+            Outcome::parse(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Outcome::AlreadySolved
+        );
+        assert_eq!(Outcome::parse("Something else"), Outcome::Unknown);
+    }
+
+    #[test]
+    fn parses_rate_limit_wait_time() {
+        assert_eq!(
+            Outcome::parse("You gave an answer too recently. You have 1m 5s left to wait."),
+            Outcome::RateLimited(Some(Duration::from_secs(65)))
+        );
+        assert_eq!(
+            Outcome::parse("You gave an answer too recently. You have 37s left to wait."),
+            Outcome::RateLimited(Some(Duration::from_secs(37)))
+        );
+        assert_eq!(
+            Outcome::parse("You gave an answer too recently."),
+            Outcome::RateLimited(None)
+        );
+        assert_eq!(
+            Outcome::parse("You gave an answer too recently. You have 5… left to wait."),
+            Outcome::RateLimited(None)
+        );
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let attempts = [attempt("abc", Outcome::Incorrect)];
+        assert_eq!(
+            check(&attempts, "abc"),
+            Err(Refusal::KnownWrong(Outcome::Incorrect))
+        );
+        assert_eq!(check(&attempts, "abd"), Ok(()));
+    }
+
+    #[test]
+    fn refuses_answers_outside_bounds() {
+        let attempts = [
+            attempt("100", Outcome::TooHigh),
+            attempt("200", Outcome::TooHigh),
+            attempt("10", Outcome::TooLow),
+            attempt("50", Outcome::RateLimited(None)),
+        ];
+        assert_eq!(check(&attempts, "101"), Err(Refusal::AboveBound(100)));
+        assert_eq!(check(&attempts, "150"), Err(Refusal::AboveBound(100)));
+        assert_eq!(check(&attempts, "9"), Err(Refusal::BelowBound(10)));
+        assert_eq!(check(&attempts, "-5"), Err(Refusal::BelowBound(10)));
+        assert_eq!(check(&attempts, "50"), Ok(()));
+        assert_eq!(check(&attempts, "not a number"), Ok(()));
+    }
+
+    #[test]
+    fn roundtrips_submissions() {
+        let submission = attempt("a\nb", Outcome::TooLow);
+        let line = JsonValue::from(&submission).stringify().unwrap();
+        assert_eq!(Submission::try_from(line.as_str()).unwrap(), submission);
+    }
+
+    #[test]
+    fn skips_malformed_submissions() {
+        let contents = [
+            r#"{"timestamp":1,"day":"01","part":1,"answer":"5","outcome":"too_low"}"#,
+            r#"{"timestamp":2,"day":"01","part":1,"answer":"5","outcome":"bogus"}"#,
+            "",
+        ]
+        .join("\n");
+
+        assert_eq!(parse_submissions(&contents).len(), 1);
+    }
+}
@@ -1,19 +1,76 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{collections::HashMap, fs, io::Error, str::FromStr, time::Duration};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{runner::BenchStats, Day, Year};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+fn get_path(year: Year) -> String {
+    format!("./data/{year}/timings.json")
+}
+
+/// Current version of the timings file schema.
+/// Version 1 files (no `version` key) stored parts as preformatted strings and are migrated on read.
+const TIMINGS_VERSION: u8 = 2;
+
+/// Represents benchmark times for a single part of a day.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartTiming {
+    pub nanos: f64,
+    /// Number of samples the time was averaged over, `0` if unknown (migrated from a version 1 file).
+    pub samples: u128,
+    pub stats: Option<BenchStats>,
+}
+
+impl PartTiming {
+    pub fn duration(&self) -> Duration {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Duration::from_nanos(self.nanos.round() as u64)
+    }
+}
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    /// Time spent parsing the input, only present for solutions that implement `template::Solution`.
+    pub parse: Option<PartTiming>,
+    pub part_1: Option<PartTiming>,
+    pub part_2: Option<PartTiming>,
     pub total_nanos: f64,
 }
 
+impl Timing {
+    /// The timing of a phase of the day, where phase 0 is the parse step.
+    pub fn part(&self, part: u8) -> Option<&PartTiming> {
+        match part {
+            0 => self.parse.as_ref(),
+            1 => self.part_1.as_ref(),
+            2 => self.part_2.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// Change in runtime of a single part between two sets of timings.
+#[derive(Clone, Debug)]
+pub struct TimingChange {
+    pub day: Day,
+    pub part: u8,
+    pub old_nanos: f64,
+    pub new_nanos: f64,
+}
+
+impl TimingChange {
+    /// Relative change in percent, positive values mean the part got slower.
+    pub fn percent(&self) -> f64 {
+        (self.new_nanos - self.old_nanos) / self.old_nanos * 100_f64
+    }
+
+    /// Whether the part got slower by more than `threshold` percent.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.percent() > threshold
+    }
+}
+
 /// Represents benchmark times for a set of days.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
@@ -22,16 +79,16 @@ pub struct Timings {
 }
 
 impl Timings {
-    /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    /// Dehydrate timings of a year to a JSON file.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(get_path(year))?;
         json.format_to(&mut file)
     }
 
-    /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        let s = fs::read_to_string(TIMINGS_FILE_PATH)
+    /// Rehydrate timings of a year from a JSON file. If not present, returns empty timings.
+    pub fn read_from_file(year: Year) -> Self {
+        let s = fs::read_to_string(get_path(year))
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from);
 
@@ -72,6 +129,33 @@ impl Timings {
             .iter()
             .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
     }
+
+    /// Compare `new` timings against `self`, for every day and phase that is present in both.
+    pub fn compare(&self, new: &Self) -> Vec<TimingChange> {
+        let mut changes = vec![];
+
+        for timing in &new.data {
+            let Some(old) = self.data.iter().find(|t| t.day == timing.day) else {
+                continue;
+            };
+
+            for part in [0, 1, 2] {
+                if let (Some(old_part), Some(new_part)) = (old.part(part), timing.part(part)) {
+                    if old_part.nanos > 0_f64 {
+                        changes.push(TimingChange {
+                            day: timing.day,
+                            part,
+                            old_nanos: old_part.nanos,
+                            new_nanos: new_part.nanos,
+                        });
+                    }
+                }
+            }
+        }
+
+        changes.sort_unstable_by_key(|c| (c.day, c.part));
+        changes
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -80,6 +164,11 @@ impl From<Timings> for JsonValue {
     fn from(value: Timings) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
+        map.insert(
+            "version".into(),
+            JsonValue::Number(f64::from(TIMINGS_VERSION)),
+        );
+
         map.insert(
             "data".into(),
             JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
@@ -95,9 +184,25 @@ impl TryFrom<String> for Timings {
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
 
-        let json_data = json
+        let json = json
             .get::<HashMap<String, JsonValue>>()
-            .ok_or("expected JSON document to be an object.")?
+            .ok_or("expected JSON document to be an object.")?;
+
+        // NOTE: files written before the schema was versioned do not have a `version` key.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let version = match json.get("version") {
+            Some(v) => v
+                .get::<f64>()
+                .map(|x| *x as u8)
+                .ok_or("expected `json.version` to be a number.")?,
+            None => 1,
+        };
+
+        if version == 0 || version > TIMINGS_VERSION {
+            return Err(format!("unsupported timings file version {version}."));
+        }
+
+        let json_data = json
             .get("data")
             .ok_or("expected JSON document to have key `data`.")?
             .get::<Vec<JsonValue>>()
@@ -106,7 +211,13 @@ impl TryFrom<String> for Timings {
         Ok(Timings {
             data: json_data
                 .iter()
-                .map(Timing::try_from)
+                .map(|timing| {
+                    if version == 1 {
+                        Timing::try_from_v1(timing)
+                    } else {
+                        Timing::try_from(timing)
+                    }
+                })
                 .collect::<Result<_, _>>()?,
         })
     }
@@ -114,6 +225,61 @@ impl TryFrom<String> for Timings {
 
 /* -------------------------------------------------------------------------- */
 
+impl From<&PartTiming> for JsonValue {
+    fn from(value: &PartTiming) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("nanos".into(), JsonValue::Number(value.nanos));
+
+        #[allow(clippy::cast_precision_loss)]
+        let samples = value.samples as f64;
+        map.insert("samples".into(), JsonValue::Number(samples));
+
+        map.insert(
+            "stats".into(),
+            match &value.stats {
+                Some(x) => JsonValue::from(x),
+                None => JsonValue::Null,
+            },
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartTiming {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part timing to be a JSON object.")?;
+
+        let nanos = json
+            .get("nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected part.nanos to be a number.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let samples = json
+            .get("samples")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u128)
+            .ok_or("Expected part.samples to be a number.")?;
+
+        let stats = match json.get("stats") {
+            Some(v) if !v.is_null() => Some(BenchStats::try_from(v)?),
+            _ => None,
+        };
+
+        Ok(PartTiming {
+            nanos,
+            samples,
+            stats,
+        })
+    }
+}
+
 impl From<&Timing> for JsonValue {
     fn from(value: &Timing) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
@@ -121,21 +287,26 @@ impl From<&Timing> for JsonValue {
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
+        map.insert(
+            "parse".into(),
+            match &value.parse {
+                Some(x) => JsonValue::from(x),
+                None => JsonValue::Null,
+            },
+        );
 
         map.insert(
             "part_1".into(),
-            match part_1 {
-                Some(x) => x,
+            match &value.part_1 {
+                Some(x) => JsonValue::from(x),
                 None => JsonValue::Null,
             },
         );
 
         map.insert(
             "part_2".into(),
-            match part_2 {
-                Some(x) => x,
+            match &value.part_2 {
+                Some(x) => JsonValue::from(x),
                 None => JsonValue::Null,
             },
         );
@@ -152,62 +323,135 @@ impl TryFrom<&JsonValue> for Timing {
             .get::<HashMap<String, JsonValue>>()
             .ok_or("Expected timing to be a JSON object.")?;
 
-        let day = json
-            .get("day")
-            .and_then(|v| v.get::<String>())
-            .and_then(|day| Day::from_str(day).ok())
-            .ok_or("Expected timing.day to be a Day struct.")?;
+        let day = parse_day(json)?;
+        let total_nanos = parse_total_nanos(json)?;
 
-        let part_1 = json
-            .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
+        let parse_part = |key: &str| match json.get(key) {
+            Some(v) if v.is_null() => Ok(None),
+            Some(v) => PartTiming::try_from(v).map(Some),
+            None => Err(format!("Expected timing.{key} to be null or an object.")),
+        };
 
-        let part_2 = json
-            .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
+        // NOTE: the parse step is optional, days written before it existed do not have a `parse` key.
+        let parse = match json.get("parse") {
+            Some(v) if !v.is_null() => Some(PartTiming::try_from(v)?),
+            _ => None,
+        };
 
-        let total_nanos = json
-            .get("total_nanos")
-            .and_then(|v| v.get::<f64>().copied())
-            .ok_or("Expected timing.total_nanos to be a number.")?;
+        Ok(Timing {
+            day,
+            parse,
+            part_1: parse_part("part_1")?,
+            part_2: parse_part("part_2")?,
+            total_nanos,
+        })
+    }
+}
+
+impl Timing {
+    /// Read a timing from a version 1 file, where parts are preformatted strings like `"74.13ms"`.
+    fn try_from_v1(value: &JsonValue) -> Result<Self, String> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing to be a JSON object.")?;
+
+        let day = parse_day(json)?;
+        let total_nanos = parse_total_nanos(json)?;
+
+        let parse_part = |key: &str| -> Result<Option<PartTiming>, String> {
+            let Some(part) = json
+                .get(key)
+                .map(|v| if v.is_null() { None } else { v.get::<String>() })
+                .ok_or(format!("Expected timing.{key} to be null or string."))?
+            else {
+                return Ok(None);
+            };
+
+            let nanos =
+                parse_duration(part).ok_or(format!("Could not parse timing.{key} `{part}`."))?;
+
+            Ok(Some(PartTiming {
+                nanos,
+                samples: 0,
+                stats: None,
+            }))
+        };
 
         Ok(Timing {
             day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
+            parse: None,
+            part_1: parse_part("part_1")?,
+            part_2: parse_part("part_2")?,
             total_nanos,
         })
     }
 }
 
+fn parse_day(json: &HashMap<String, JsonValue>) -> Result<Day, String> {
+    json.get("day")
+        .and_then(|v| v.get::<String>())
+        .and_then(|day| Day::from_str(day).ok())
+        .ok_or("Expected timing.day to be a Day struct.".into())
+}
+
+fn parse_total_nanos(json: &HashMap<String, JsonValue>) -> Result<f64, String> {
+    json.get("total_nanos")
+        .and_then(|v| v.get::<f64>().copied())
+        .ok_or("Expected timing.total_nanos to be a number.".into())
+}
+
+fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
+    s.split(postfix).next()?.parse().ok()
+}
+
+/// Parse a duration formatted with `{:?}` to nanoseconds.
+fn parse_duration(s: &str) -> Option<f64> {
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    match s {
+        s if s.contains("ns") => parse_to_float(s, "ns"),
+        s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
+        s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
+        s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
+    }
+}
+
 /* -------------------------------------------------------------------------- */
 
 #[cfg(feature = "test_lib")]
 mod tests {
     use crate::day;
 
-    use super::{Timing, Timings};
+    use super::{PartTiming, Timing, Timings};
+
+    pub fn ms(millis: f64) -> Option<PartTiming> {
+        Some(PartTiming {
+            nanos: millis * 1_000_000_f64,
+            samples: 10,
+            stats: None,
+        })
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    parse: None,
+                    part_1: ms(10.0),
+                    part_2: ms(20.0),
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    parse: None,
+                    part_1: ms(30.0),
+                    part_2: ms(40.0),
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
+                    parse: None,
+                    part_1: ms(40.0),
                     part_2: None,
                     total_nanos: 4e+10,
                 },
@@ -217,17 +461,55 @@ mod tests {
 
     mod deserialization {
         use crate::{day, template::timings::Timings};
+        use std::time::Duration;
 
         #[test]
         fn handles_json_timings() {
-            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "part_1": { "nanos": 1000000, "samples": 100, "stats": null }, "part_2": null, "total_nanos": 1000000 }] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
             assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
+            let part_1 = timing.part_1.as_ref().unwrap();
+            assert_eq!(part_1.nanos, 1_000_000_f64);
+            assert_eq!(part_1.samples, 100);
+            assert_eq!(part_1.duration(), Duration::from_millis(1));
             assert_eq!(timing.part_2, None);
-            assert_eq!(timing.total_nanos, 1_000_000_000_f64);
+            assert_eq!(timing.total_nanos, 1_000_000_f64);
+        }
+
+        #[test]
+        fn handles_json_timings_with_parse() {
+            let json = r#"{ "version": 2, "data": [{ "day": "05", "parse": { "nanos": 2000, "samples": 100, "stats": null }, "part_1": { "nanos": 1000, "samples": 100, "stats": null }, "part_2": null, "total_nanos": 3000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.parse.as_ref().unwrap().nanos, 2000_f64);
+            assert_eq!(timing.part(0), timing.parse.as_ref());
+            assert_eq!(timing.part_1.as_ref().unwrap().nanos, 1000_f64);
+        }
+
+        #[test]
+        fn handles_json_timings_with_stats() {
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "part_1": { "nanos": 1000000, "samples": 100, "stats": { "median_nanos": 990000, "p95_nanos": 1200000, "min_nanos": 900000, "max_nanos": 1300000, "std_dev_nanos": 50000, "outliers": 2, "first_call_nanos": null, "inconsistent": 0 } }, "part_2": null, "total_nanos": 1000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            let stats = timing.part_1.as_ref().unwrap().stats.unwrap();
+            assert_eq!(stats.median, Duration::from_nanos(990_000));
+            assert_eq!(stats.p95, Duration::from_nanos(1_200_000));
+            assert_eq!(stats.outliers, 2);
+            assert_eq!(stats.first_call, None);
+        }
+
+        #[test]
+        fn migrates_version_1_timings() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": "74.13ns", "total_nanos": 1000074.13 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            let part_1 = timing.part_1.as_ref().unwrap();
+            assert_eq!(part_1.nanos, 1_000_000_f64);
+            assert_eq!(part_1.samples, 0);
+            assert_eq!(part_1.stats, None);
+            assert!((timing.part_2.as_ref().unwrap().nanos - 74.13).abs() < 1e-6);
         }
 
         #[test]
@@ -244,16 +526,31 @@ mod tests {
             Timings::try_from(json).unwrap();
         }
 
+        #[test]
+        #[should_panic]
+        fn panics_for_unsupported_version() {
+            let json = r#"{ "version": 3, "data": [] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
+
         #[test]
         #[should_panic]
         fn panics_for_malformed_timings() {
             let json = r#"{ "data": [{ "day": "01" }, { "day": "26" }, { "day": "02", "part_2": null, "total_nanos": 0 }] }"#.to_string();
             Timings::try_from(json).unwrap();
         }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_string_parts_in_version_2() {
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 0 }] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
     }
 
     mod serialization {
         use super::get_mock_timings;
+        use crate::template::timings::Timings;
         use std::collections::HashMap;
         use tinyjson::JsonValue;
 
@@ -261,11 +558,10 @@ mod tests {
         fn serializes_timings() {
             let timings = get_mock_timings();
             let value = JsonValue::try_from(timings).unwrap();
+            let json = value.get::<HashMap<String, JsonValue>>().unwrap();
+            assert_eq!(json.get("version").unwrap().get::<f64>(), Some(&2_f64));
             assert_eq!(
-                value
-                    .get::<HashMap<String, JsonValue>>()
-                    .unwrap()
-                    .get("data")
+                json.get("data")
                     .unwrap()
                     .get::<Vec<JsonValue>>()
                     .unwrap()
@@ -273,6 +569,16 @@ mod tests {
                 3
             );
         }
+
+        #[test]
+        fn roundtrips_timings() {
+            let timings = get_mock_timings();
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            assert_eq!(parsed.data.len(), 3);
+            assert_eq!(parsed.data[1].part_1, timings.data[1].part_1);
+            assert_eq!(parsed.data[2].part_2, None);
+        }
     }
 
     mod is_day_complete {
@@ -281,18 +587,21 @@ mod tests {
             template::timings::{Timing, Timings},
         };
 
+        use super::ms;
+
         #[test]
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
+                    parse: None,
+                    part_1: ms(1.0),
+                    part_2: ms(2.0),
                     total_nanos: 3_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -300,13 +609,14 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
+                    parse: None,
+                    part_1: ms(1.0),
                     part_2: None,
                     total_nanos: 1_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -314,13 +624,14 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
+                    parse: None,
                     part_1: None,
                     part_2: None,
                     total_nanos: 0.0,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
@@ -338,6 +649,7 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(3),
+                    parse: None,
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
@@ -358,6 +670,7 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(2),
+                    parse: None,
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
@@ -388,4 +701,90 @@ mod tests {
             assert_eq!(merged.data.len(), 3);
         }
     }
+
+    mod compare {
+        use crate::{
+            day,
+            template::timings::{Timing, Timings},
+        };
+
+        use super::{get_mock_timings, ms};
+
+        #[test]
+        fn compares_overlapping_parts() {
+            let timings = get_mock_timings();
+            let other = Timings {
+                data: vec![Timing {
+                    day: day!(2),
+                    parse: None,
+                    part_1: ms(45.0),
+                    part_2: ms(20.0),
+                    total_nanos: 0_f64,
+                }],
+            };
+            let changes = timings.compare(&other);
+
+            assert_eq!(changes.len(), 2);
+            assert_eq!(changes[0].day, day!(2));
+            assert_eq!(changes[0].part, 1);
+            assert!((changes[0].percent() - 50_f64).abs() < 1e-6);
+            assert!(changes[0].is_regression(10_f64));
+            assert!(!changes[0].is_regression(50_f64));
+            assert!((changes[1].percent() + 50_f64).abs() < 1e-6);
+            assert!(!changes[1].is_regression(10_f64));
+        }
+
+        #[test]
+        fn skips_missing_parts() {
+            let timings = get_mock_timings();
+            let other = Timings {
+                data: vec![Timing {
+                    day: day!(4),
+                    parse: None,
+                    part_1: ms(40.0),
+                    part_2: ms(10.0),
+                    total_nanos: 0_f64,
+                }],
+            };
+            let changes = timings.compare(&other);
+
+            assert_eq!(changes.len(), 1);
+            assert_eq!(changes[0].part, 1);
+        }
+
+        #[test]
+        fn compares_parse_steps() {
+            let old = Timings {
+                data: vec![Timing {
+                    day: day!(5),
+                    parse: ms(10.0),
+                    part_1: ms(10.0),
+                    part_2: None,
+                    total_nanos: 0_f64,
+                }],
+            };
+            let new = Timings {
+                data: vec![Timing {
+                    day: day!(5),
+                    parse: ms(20.0),
+                    part_1: ms(10.0),
+                    part_2: None,
+                    total_nanos: 0_f64,
+                }],
+            };
+            let changes = old.compare(&new);
+
+            assert_eq!(changes.len(), 2);
+            assert_eq!(changes[0].part, 0);
+            assert!(changes[0].is_regression(10_f64));
+            assert_eq!(changes[1].part, 1);
+        }
+
+        #[test]
+        fn skips_new_days() {
+            let timings = Timings::default();
+            let changes = timings.compare(&get_mock_timings());
+            assert!(changes.is_empty());
+        }
+    }
 }
@@ -0,0 +1,380 @@
+/// Append-only log of benchmark results, used to show how the runtime of a day evolved over time.
+/// Each line of the history file is a JSON object for a single day.
+use std::{
+    collections::HashMap,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    process::Command,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::runner::{phase_name, PARSE_PHASE};
+use crate::template::timings::Timings;
+use crate::template::{Day, PuzzleId, Year};
+
+fn get_path(year: Year) -> String {
+    format!("./data/{year}/timings_history.jsonl")
+}
+
+const SPARKLINE_CHARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Benchmark times of a single day at some point in time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistoryEntry {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// Short hash of the git HEAD the timings were taken at, if available.
+    pub commit: Option<String>,
+    pub day: Day,
+    /// Only recorded for solutions with a separate parse step.
+    pub parse: Option<f64>,
+    pub part_1: Option<f64>,
+    pub part_2: Option<f64>,
+}
+
+impl HistoryEntry {
+    pub fn part(&self, part: u8) -> Option<f64> {
+        match part {
+            0 => self.parse,
+            1 => self.part_1,
+            2 => self.part_2,
+            _ => None,
+        }
+    }
+}
+
+/// Append an entry for every day in `timings` to the history file of a year.
+pub fn append(year: Year, timings: &Timings) -> Result<(), io::Error> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |x| x.as_secs());
+
+    let commit = get_git_head();
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(get_path(year))?;
+
+    for timing in &timings.data {
+        let entry = HistoryEntry {
+            timestamp,
+            commit: commit.clone(),
+            day: timing.day,
+            parse: timing.parse.as_ref().map(|x| x.nanos),
+            part_1: timing.part_1.as_ref().map(|x| x.nanos),
+            part_2: timing.part_2.as_ref().map(|x| x.nanos),
+        };
+
+        let line = JsonValue::from(&entry)
+            .stringify()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+
+        writeln!(file, "{line}")?;
+    }
+
+    Ok(())
+}
+
+/// Read all history entries for a puzzle, oldest first. If the history file is not present, returns no entries.
+pub fn read_for_puzzle(puzzle: PuzzleId) -> Vec<HistoryEntry> {
+    let Ok(contents) = fs::read_to_string(get_path(puzzle.year)) else {
+        return vec![];
+    };
+
+    parse_entries(&contents)
+        .into_iter()
+        .filter(|entry| entry.day == puzzle.day)
+        .collect()
+}
+
+fn parse_entries(contents: &str) -> Vec<HistoryEntry> {
+    let mut entries: Vec<HistoryEntry> = contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match HistoryEntry::try_from(line) {
+            Ok(entry) => Some(entry),
+            Err(e) => {
+                eprintln!("Skipping malformed history entry: {e}");
+                None
+            }
+        })
+        .collect();
+
+    entries.sort_by_key(|entry| entry.timestamp);
+    entries
+}
+
+fn get_git_head() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let hash = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (!hash.is_empty()).then_some(hash)
+}
+
+/// Render a series of values as a sparkline, scaled between the smallest and largest value.
+pub fn sparkline(values: &[f64]) -> String {
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+    values
+        .iter()
+        .map(|value| {
+            if max - min <= f64::EPSILON {
+                return SPARKLINE_CHARS[0];
+            }
+
+            #[allow(
+                clippy::cast_possible_truncation,
+                clippy::cast_sign_loss,
+                clippy::cast_precision_loss
+            )]
+            let index =
+                ((value - min) / (max - min) * (SPARKLINE_CHARS.len() - 1) as f64).round() as usize;
+
+            SPARKLINE_CHARS[index]
+        })
+        .collect()
+}
+
+/// Format a unix timestamp as an ISO 8601 date (`YYYY-MM-DD`) in UTC.
+pub fn format_date(timestamp: u64) -> String {
+    // see: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    #[allow(clippy::cast_possible_wrap)]
+    let days = (timestamp / 86400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// Print the runtime history of a day as a table, followed by a sparkline per part.
+/// The parse step is only shown if it was recorded for any entry.
+pub fn print_history(puzzle: PuzzleId, entries: &[HistoryEntry]) {
+    if entries.is_empty() {
+        println!(
+            "No history recorded for day {}. Run `cargo time {} --year {} --store` to record one.",
+            puzzle.day, puzzle.day, puzzle.year
+        );
+        return;
+    }
+
+    let phases: Vec<u8> = if entries.iter().any(|e| e.parse.is_some()) {
+        vec![PARSE_PHASE, 1, 2]
+    } else {
+        vec![1, 2]
+    };
+
+    let header: String = phases
+        .iter()
+        .map(|part| format!("  {:>12}", phase_name(*part)))
+        .collect();
+    println!("{:<10}  {:<9}{header}", "Date", "Commit");
+
+    for entry in entries {
+        let cells: String = phases
+            .iter()
+            .map(|part| format!("  {:>12}", format_nanos(entry.part(*part))))
+            .collect();
+
+        println!(
+            "{:<10}  {:<9}{cells}",
+            format_date(entry.timestamp),
+            entry.commit.as_deref().unwrap_or("-"),
+        );
+    }
+
+    println!();
+
+    for part in phases {
+        let values: Vec<f64> = entries.iter().filter_map(|e| e.part(part)).collect();
+
+        if let (Some(first), Some(last)) = (values.first(), values.last()) {
+            println!(
+                "{}: {} ({} -> {})",
+                phase_name(part),
+                sparkline(&values),
+                format_nanos(Some(*first)),
+                format_nanos(Some(*last)),
+            );
+        }
+    }
+}
+
+fn format_nanos(nanos: Option<f64>) -> String {
+    match nanos {
+        Some(nanos) => {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let duration = Duration::from_nanos(nanos.round() as u64);
+            format!("{duration:.1?}")
+        }
+        None => "-".into(),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&HistoryEntry> for JsonValue {
+    fn from(value: &HistoryEntry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        #[allow(clippy::cast_precision_loss)]
+        let timestamp = value.timestamp as f64;
+        map.insert("timestamp".into(), JsonValue::Number(timestamp));
+
+        map.insert(
+            "commit".into(),
+            match &value.commit {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+
+        for (key, part) in [
+            ("parse", value.parse),
+            ("part_1", value.part_1),
+            ("part_2", value.part_2),
+        ] {
+            map.insert(
+                key.into(),
+                match part {
+                    Some(x) => JsonValue::Number(x),
+                    None => JsonValue::Null,
+                },
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&str> for HistoryEntry {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(value).or(Err("not valid JSON."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u64)
+            .ok_or("Expected entry.timestamp to be a number.")?;
+
+        let commit = json
+            .get("commit")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected entry.commit to be null or string.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected entry.day to be a Day struct.")?;
+
+        let parse_part = |key: &str| {
+            json.get(key)
+                .map(|v| if v.is_null() { None } else { v.get::<f64>() })
+                .ok_or(format!("Expected entry.{key} to be null or a number."))
+        };
+
+        // NOTE: entries written before the parse step was recorded do not have a `parse` key.
+        let parse = match json.get("parse") {
+            Some(v) if !v.is_null() => Some(
+                *v.get::<f64>()
+                    .ok_or("Expected entry.parse to be null or a number.")?,
+            ),
+            _ => None,
+        };
+
+        Ok(HistoryEntry {
+            timestamp,
+            commit: commit.cloned(),
+            day,
+            parse,
+            part_1: parse_part("part_1")?.copied(),
+            part_2: parse_part("part_2")?.copied(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_date, parse_entries, sparkline, HistoryEntry};
+    use crate::day;
+    use tinyjson::JsonValue;
+
+    #[test]
+    fn formats_dates() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(951_782_400), "2000-02-29");
+        assert_eq!(format_date(1_701_475_199), "2023-12-01");
+    }
+
+    #[test]
+    fn renders_sparklines() {
+        assert_eq!(sparkline(&[1.0, 8.0, 4.5]), "▁█▅");
+        assert_eq!(sparkline(&[3.0, 3.0]), "▁▁");
+        assert_eq!(sparkline(&[]), "");
+    }
+
+    #[test]
+    fn parses_entries_in_order() {
+        let contents = [
+            r#"{"timestamp":200,"commit":"abc1234","day":"01","part_1":20,"part_2":null}"#,
+            "",
+            "not json",
+            r#"{"timestamp":100,"commit":null,"day":"01","part_1":10,"part_2":30}"#,
+        ]
+        .join("\n");
+
+        let entries = parse_entries(&contents);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].timestamp, 100);
+        assert_eq!(entries[0].commit, None);
+        assert_eq!(entries[0].part_2, Some(30.0));
+        assert_eq!(entries[1].commit, Some("abc1234".into()));
+        assert_eq!(entries[1].part_2, None);
+        assert_eq!(entries[1].parse, None);
+    }
+
+    #[test]
+    fn roundtrips_entries() {
+        let entry = HistoryEntry {
+            timestamp: 1_701_475_199,
+            commit: Some("abc1234".into()),
+            day: day!(12),
+            parse: Some(300.0),
+            part_1: Some(1500.0),
+            part_2: None,
+        };
+
+        let line = JsonValue::from(&entry).stringify().unwrap();
+        assert_eq!(HistoryEntry::try_from(line.as_str()).unwrap(), entry);
+    }
+}
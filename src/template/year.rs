@@ -0,0 +1,160 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The first year advent of code took place.
+const FIRST_YEAR: u16 = 2015;
+
+/// A valid advent of code year (i.e. an integer from 2015 onwards).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2023).unwrap();
+/// assert_eq!(year.to_string(), "2023")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        if !(FIRST_YEAR..=9999).contains(&year) {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: u16) -> Self {
+        Self(year)
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// Returns the default year set via the `AOC_YEAR` environment variable, if any.
+    pub fn from_env() -> Option<Self> {
+        env::var("AOC_YEAR").ok()?.parse().ok()
+    }
+
+    /// Reads the year from the module path of a solution.
+    /// Solutions are named `<year>-<day>`, so the crate of a solution binary is called `<year>_<day>`.
+    /// The runner includes them as modules called `_<year>_<day>` instead, as identifiers cannot start with a digit.
+    ///
+    /// # Panics
+    /// Panics (at compile time, when used in a const context) if the last path segment is not named like a solution.
+    pub const fn from_module_path(path: &str) -> Self {
+        let bytes = path.as_bytes();
+
+        let mut start = bytes.len();
+        while start > 0 && bytes[start - 1] != b':' {
+            start -= 1;
+        }
+
+        if start < bytes.len() && bytes[start] == b'_' {
+            start += 1;
+        }
+
+        assert!(
+            bytes.len() >= start + 4,
+            "solutions must be named `<year>-<day>`, e.g. `2023-01.rs`"
+        );
+
+        let mut year = 0;
+        let mut i = start;
+        while i < start + 4 {
+            assert!(
+                bytes[i].is_ascii_digit(),
+                "solutions must be named `<year>-<day>`, e.g. `2023-01.rs`"
+            );
+            year = year * 10 + (bytes[i] - b'0') as u16;
+            i += 1;
+        }
+
+        Self(year)
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year from 2015 onwards")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {{
+        const _ASSERT: () = assert!(
+            $year >= 2015 && $year <= 9999,
+            concat!(
+                "invalid year `",
+                $year,
+                "`, expecting a value from 2015 onwards"
+            ),
+        );
+        $crate::template::Year::__new_unchecked($year)
+    }};
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Year;
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2023".parse::<Year>().unwrap(), Year(2023));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("23".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn reads_year_from_module_path() {
+        assert_eq!(Year::from_module_path("2023_01"), Year(2023));
+        assert_eq!(
+            Year::from_module_path("advent_of_code::solutions::_2016_25"),
+            Year(2016)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_unexpected_module_paths() {
+        let _ = Year::from_module_path("_01");
+    }
+}